@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use super::common::{Baccarat, Result};
-use games::BetSerde;
+use super::lucky::{self, LuckyBet, SUPER6};
+use super::pairs::{PairBet, PairConfig};
+use super::dragon_bonus::{self, Side};
+use games::{BetSerde, Game};
 
 #[derive(Hash, PartialEq, Eq, Debug)]
 pub enum Bets {
@@ -16,6 +19,8 @@ pub enum Bets {
     Super6,
     Big,
     Small,
+    BankerDragonBonus,
+    PlayerDragonBonus,
 }
 
 use self::Bets::*;
@@ -35,6 +40,8 @@ impl BetSerde for Bets {
             Super6 => 10,
             Big => 11,
             Small => 12,
+            BankerDragonBonus => 13,
+            PlayerDragonBonus => 14,
         }
     }
 
@@ -52,17 +59,21 @@ impl BetSerde for Bets {
             10 => Some(Super6),
             11 => Some(Big),
             12 => Some(Small),
+            13 => Some(BankerDragonBonus),
+            14 => Some(PlayerDragonBonus),
             _ => None,
         }
     }
 }
 
 lazy_static! {
-    static ref ALL_BETS:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerN8, PlayerN8, BankerN9, PlayerN9, Super6, BankerPair, PlayerPair, Big, Small };
-    static ref BETS_AFTER40:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerN8, PlayerN8, BankerN9, PlayerN9, Super6, BankerPair,PlayerPair};
-    static ref BETS_AFTER70:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerN8, PlayerN8, BankerN9, PlayerN9, Super6};
+    static ref ALL_BETS:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerN8, PlayerN8, BankerN9, PlayerN9, Super6, BankerPair, PlayerPair, Big, Small, BankerDragonBonus, PlayerDragonBonus };
+    static ref BETS_AFTER40:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerN8, PlayerN8, BankerN9, PlayerN9, Super6, BankerPair,PlayerPair, BankerDragonBonus, PlayerDragonBonus};
+    static ref BETS_AFTER70:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerN8, PlayerN8, BankerN9, PlayerN9, Super6, BankerDragonBonus, PlayerDragonBonus};
 }
 
+pub const MAIN_BETS: [Bets; 3] = [Banker, Player, Tie];
+
 pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
     if hands <= 40 {
         &ALL_BETS
@@ -75,20 +86,51 @@ pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
 
 pub struct CommissionBaccaratGame;
 
+impl Game for CommissionBaccaratGame {
+    type B = Bets;
+
+    fn name(&self) -> &'static str {
+        "commission_baccarat"
+    }
+
+    fn main_bets(&self) -> &[Bets] {
+        &MAIN_BETS
+    }
+}
+
+// only the banker and player pairs have a spot on this layout
+pub const PAIR_BETS: [PairBet; 2] = [PairBet::BankerPair, PairBet::PlayerPair];
+
 pub fn payout_map(b: &Baccarat) -> HashMap<Bets, f64> {
+    pair_payout_map(b, &PairConfig::default())
+}
+
+// None when the config has a pair bet outside PAIR_BETS
+pub fn payout_map_with(b: &Baccarat, pairs: &PairConfig) -> Option<HashMap<Bets, f64>> {
+    if !pairs.validate(&PAIR_BETS) {
+        return None;
+    }
+    Some(pair_payout_map(b, pairs))
+}
+
+fn pair_payout_map(b: &Baccarat, pairs: &PairConfig) -> HashMap<Bets, f64> {
     let result = b.result();
     let mut map = result_payout_map(result);
-    side_bet(b, result, &mut map);
+    side_bet(b, pairs, &mut map);
     map
 }
 
+fn pair_spot(bet: PairBet) -> Option<Bets> {
+    match bet {
+        PairBet::BankerPair => Some(BankerPair),
+        PairBet::PlayerPair => Some(PlayerPair),
+        _ => None,
+    }
+}
+
 #[inline]
-fn side_bet(b: &Baccarat, result: Result, map: &mut HashMap<Bets, f64>) {
-    if let Result::Banker(6) = result {
-        let r = match b.banker_total_cards() {
-            3 => 19.0,
-            _ => 13.0,
-        };
+fn side_bet(b: &Baccarat, pairs: &PairConfig, map: &mut HashMap<Bets, f64>) {
+    if let Some(r) = lucky::payout(b, LuckyBet::BankerWins6, &SUPER6) {
         map.insert(Super6, r);
     }
     if b.total_cards() > 4 {
@@ -96,13 +138,16 @@ fn side_bet(b: &Baccarat, result: Result, map: &mut HashMap<Bets, f64>) {
     } else {
         map.insert(Small, 2.5);
     }
-    let (b1, b2) = b.banker_first2();
-    if b1.is_same_rank(&b2) {
-        map.insert(BankerPair, 12.0);
+    for (bet, r) in pairs.payouts(b) {
+        if let Some(spot) = pair_spot(bet) {
+            map.insert(spot, r);
+        }
+    }
+    if let Some(r) = dragon_bonus::payout(b, Side::Banker) {
+        map.insert(BankerDragonBonus, r);
     }
-    let (p1, p2) = b.player_first2();
-    if p1.is_same_rank(&p2) {
-        map.insert(PlayerPair, 12.0);
+    if let Some(r) = dragon_bonus::payout(b, Side::Player) {
+        map.insert(PlayerDragonBonus, r);
     }
 }
 
@@ -144,11 +189,11 @@ mod tests {
     #[test]
     fn test_valid_bets() {
         let r = valid_bets(1);
-        assert_eq!(12, r.len());
+        assert_eq!(14, r.len());
         let r = valid_bets(41);
-        assert_eq!(10, r.len());
+        assert_eq!(12, r.len());
         let r = valid_bets(71);
-        assert_eq!(8, r.len())
+        assert_eq!(10, r.len())
     }
 
     #[test]
@@ -206,4 +251,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pair_config() {
+        use games::card::baccarat::pairs::PairPaytable;
+        use games::card::serde::str_to_card;
+        use games::card::Card;
+
+        // player S9 H9, banker DK DT
+        let cards: Vec<Card> = ["S9", "DK", "H9", "DT"].iter().map(|s| str_to_card(s).unwrap()).collect();
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(payout_map(&b)[&PlayerPair], 12.0);
+        let t = PairPaytable {
+            pair: 11.0,
+            ..PairPaytable::default()
+        };
+        let m = payout_map_with(&b, &PairConfig::new(t, vec![PairBet::PlayerPair])).unwrap();
+        assert_eq!(m[&PlayerPair], 11.0);
+        let m = payout_map_with(&b, &PairConfig::new(t, vec![PairBet::BankerPair])).unwrap();
+        assert_eq!(m.get(&PlayerPair), None);
+        // either pair wins this hand but has no spot here, so the config is refused
+        assert_eq!(payout_map_with(&b, &PairConfig::new(t, vec![PairBet::PlayerPair, PairBet::EitherPair])), None);
+        assert_eq!(payout_map_with(&b, &PairConfig::new(t, vec![PairBet::PerfectPair])), None);
+    }
 }
@@ -1,4 +1,4 @@
-use super::dealer::init_baccarat_dealer;
+use super::dealer::{init_baccarat_dealer, BaccaratDealer};
 use super::{total_points, value_of_card};
 use games::card::Card;
 
@@ -29,6 +29,14 @@ fn first2(cards: &Vec<Card>) -> (Card, Card) {
     (cards[0], cards[1])
 }
 
+fn initial_points(cards: &[Card]) -> u8 {
+    (value_of_card(&cards[0]) + value_of_card(&cards[1])) % 10
+}
+
+fn natural(cards: &[Card]) -> bool {
+    initial_points(cards) >= 8
+}
+
 fn count(cards: &[Card], v: u8) -> usize {
     cards
         .iter()
@@ -37,8 +45,8 @@ fn count(cards: &[Card], v: u8) -> usize {
 
 impl Baccarat {
     pub fn result(&self) -> Result {
-        let tb = total_points(&self.banker_cards);
-        let tp = total_points(&self.player_cards);
+        let tb = self.banker_points();
+        let tp = self.player_points();
         if tb > tp {
             Result::Banker(tb)
         } else if tb < tp {
@@ -60,8 +68,46 @@ impl Baccarat {
         self.banker_cards.len()
     }
 
+    pub fn banker_points(&self) -> u8 {
+        total_points(&self.banker_cards)
+    }
+
+    pub fn player_points(&self) -> u8 {
+        total_points(&self.player_cards)
+    }
+
+    pub fn banker_cards(&self) -> &[Card] {
+        &self.banker_cards
+    }
+
+    pub fn player_cards(&self) -> &[Card] {
+        &self.player_cards
+    }
+
+    // points of the first two cards, before any third card
+    pub fn banker_initial_points(&self) -> u8 {
+        initial_points(&self.banker_cards)
+    }
+
+    pub fn player_initial_points(&self) -> u8 {
+        initial_points(&self.player_cards)
+    }
+
+    // 8 or 9 on the first two cards
+    pub fn banker_natural(&self) -> bool {
+        natural(&self.banker_cards)
+    }
+
+    pub fn player_natural(&self) -> bool {
+        natural(&self.player_cards)
+    }
+
+    pub fn player_total_cards(&self) -> usize {
+        self.player_cards.len()
+    }
+
     pub fn total_cards(&self) -> usize {
-        self.banker_total_cards() + self.player_cards.len()
+        self.banker_total_cards() + self.player_total_cards()
     }
 
     pub fn count_cards(&self, v: u8) -> usize {
@@ -69,7 +115,11 @@ impl Baccarat {
     }
 
     pub fn from(cards: &Vec<Card>) -> Option<Baccarat> {
-        let mut init = init_baccarat_dealer();
+        Baccarat::from_dealer(init_baccarat_dealer(), cards)
+    }
+
+    // finishes a hand from a dealer that may already hold cards
+    pub fn from_dealer(mut init: BaccaratDealer, cards: &Vec<Card>) -> Option<Baccarat> {
         for c in cards {
             let result = init.deal(*c);
             if !result {
@@ -101,5 +151,8 @@ mod tests {
         let b = Baccarat::from(&cards).unwrap();
         assert_eq!(Result::Banker(9), b.result());
         assert_eq!(2, b.banker_total_cards());
+        assert_eq!((9, 2), (b.banker_points(), b.player_points()));
+        assert!(b.banker_natural());
+        assert!(!b.player_natural());
     }
 }
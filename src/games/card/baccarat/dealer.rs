@@ -1,5 +1,5 @@
 use games::card::baccarat::{total_points, value_of_card};
-use games::card::{Card, Rank, Suit};
+use games::card::{Card, Rank};
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum BaccaratStatus {
@@ -34,23 +34,44 @@ pub fn init_baccarat_dealer() -> BaccaratDealer {
     }
 }
 
-pub fn init_sevenup_dealer() -> BaccaratDealer {
-    BaccaratDealer {
+// the player starts with the seven, so the banker is dealt first
+pub fn init_sevenup_dealer(seed: Card) -> Option<BaccaratDealer> {
+    if seed.rank != Rank::Seven {
+        return None;
+    }
+    Some(BaccaratDealer {
         banker_cards: vec![],
-        player_cards: vec![
-            Card {
-                suit: Suit::Diamond,
-                rank: Rank::Seven,
-            },
-        ],
+        player_cards: vec![seed],
         status: BaccaratStatus::ExpectBanker,
-    }
+    })
+}
+
+// takes the first seven out of the shoe as the seed, so the remaining composition is right
+pub fn seed_from_shoe(shoe: &mut Vec<Card>) -> Option<BaccaratDealer> {
+    let pos = shoe.iter().position(|c| c.rank == Rank::Seven)?;
+    let seed = shoe.remove(pos);
+    init_sevenup_dealer(seed)
 }
 
 impl BaccaratDealer {
     pub fn is_done(&self) -> bool {
         self.status == BaccaratStatus::Done
     }
+    // deals from the front of the shoe until the hand is done, false when the shoe runs out
+    pub fn deal_shoe(&mut self, shoe: &mut Vec<Card>) -> bool {
+        let mut dealt = 0;
+        for &card in shoe.iter() {
+            if self.is_done() {
+                break;
+            }
+            self.deal(card);
+            dealt += 1;
+        }
+        // drop the dealt cards in one go rather than shifting the shoe per card
+        shoe.drain(..dealt);
+        self.is_done()
+    }
+
     pub fn deal(&mut self, card: Card) -> bool {
         match self.status {
             BaccaratStatus::Done => false,
@@ -112,7 +133,8 @@ impl BaccaratDealer {
 mod tests {
     use super::*;
     use games::card::serde::str_to_card;
-    use games::card::Card;
+    use games::card::{new_shoe, Card};
+    use games::rng::shuffled_shoe;
 
     fn card(s: &str) -> Card {
         str_to_card(s).unwrap()
@@ -158,6 +180,69 @@ mod tests {
         assert_eq!(result, true);
     }
 
+    #[test]
+    fn test_deal_shoe() {
+        let mut shoe = shuffled_shoe(8, &mut ::rand::thread_rng());
+        for _ in 0..50 {
+            let mut bd = init_baccarat_dealer();
+            let before = shoe.len();
+            assert!(bd.deal_shoe(&mut shoe));
+            assert!(bd.is_done());
+            assert_eq!(before - shoe.len(), bd.player_cards.len() + bd.banker_cards.len());
+        }
+        let mut bd = init_baccarat_dealer();
+        let mut short = vec![card("ST"), card("S9")];
+        assert!(!bd.deal_shoe(&mut short));
+        assert!(short.is_empty());
+        assert_eq!(vec![card("ST")], bd.player_cards);
+    }
+
+    #[test]
+    fn test_seed_from_shoe() {
+        let mut shoe = vec![card("S2"), card("H7"), card("C9"), card("D7")];
+        let bd = seed_from_shoe(&mut shoe).unwrap();
+        assert_eq!(vec![card("H7")], bd.player_cards);
+        assert_eq!(BaccaratStatus::ExpectBanker, bd.status);
+        assert_eq!(vec![card("S2"), card("C9"), card("D7")], shoe);
+        assert_eq!(vec![card("D7")], seed_from_shoe(&mut shoe).unwrap().player_cards);
+        assert!(seed_from_shoe(&mut shoe).is_none());
+        assert_eq!(vec![card("S2"), card("C9")], shoe);
+
+        let mut shoe = new_shoe(1);
+        assert!(seed_from_shoe(&mut shoe).is_some());
+        assert_eq!(51, shoe.len());
+        assert_eq!(3, shoe.iter().filter(|c| c.rank == Rank::Seven).count());
+    }
+
+    #[test]
+    fn test_provably_fair_sevenup_shoe() {
+        use games::rng::fair::ProvablyFair;
+
+        // this shoe opens C2 DT DJ HQ SQ H8 D7 S6, its first seven is card 7
+        let shoe = shuffled_shoe(8, &mut ProvablyFair::new("server", "client", 17));
+        assert_eq!(6, shoe.iter().position(|c| c.rank == Rank::Seven).unwrap());
+        let mut rest = shoe.clone();
+        let mut bd = seed_from_shoe(&mut rest).unwrap();
+        assert_eq!(vec![shoe[6]], bd.player_cards);
+        assert_eq!(&shoe[..6], &rest[..6]);
+        assert_eq!(&shoe[7..], &rest[6..]);
+
+        // hand 1 goes on from card 1 in deal order: banker, player, banker, banker draws
+        assert!(bd.deal_shoe(&mut rest));
+        assert_eq!(vec![shoe[6], shoe[1]], bd.player_cards);
+        assert_eq!(vec![shoe[0], shoe[2], shoe[3]], bd.banker_cards);
+        // the next hand starts at card 5 and card 8 follows card 6
+        assert_eq!(vec![shoe[4], shoe[5], shoe[7]], rest[..3].to_vec());
+        assert_eq!(8 * 52 - 5, rest.len());
+    }
+
+    #[test]
+    fn test_init_sevenup_dealer() {
+        assert!(init_sevenup_dealer(card("SK")).is_none());
+        let bd = init_sevenup_dealer(card("C7")).unwrap();
+        assert_eq!(vec![card("C7")], bd.player_cards);
+    }
+
     #[test]
     fn test_cards() {
         //HJS9CA#H7C3D7
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use super::common::{Baccarat, Result};
+use super::dealer::BaccaratDealer;
+use super::dragon_bonus::Side;
+use games::card::serde::card_to_str;
+use games::card::Card;
+use games::BetSerde;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SideDetail {
+    // total after the first two cards
+    pub initial: u8,
+    pub total: u8,
+    pub drew: bool,
+    pub natural: bool,
+}
+
+// a finished hand as shown to clients and kept in the history
+#[derive(Clone, PartialEq, Debug)]
+pub struct HandDetail {
+    // cards in deal order
+    pub cards: Vec<(Side, Card)>,
+    pub player: SideDetail,
+    pub banker: SideDetail,
+    pub result: Result,
+    // side bets that won and side bets that pushed, by bet id
+    pub side_bets: Vec<u16>,
+    pub pushed_side_bets: Vec<u16>,
+}
+
+impl HandDetail {
+    pub fn new(b: &Baccarat) -> HandDetail {
+        let (player_cards, banker_cards) = (b.player_cards(), b.banker_cards());
+        // player and banker take turns on the first two, then the third cards
+        let mut cards = Vec::with_capacity(6);
+        for i in 0..2 {
+            cards.push((Side::Player, player_cards[i]));
+            cards.push((Side::Banker, banker_cards[i]));
+        }
+        if let Some(&c) = player_cards.get(2) {
+            cards.push((Side::Player, c));
+        }
+        if let Some(&c) = banker_cards.get(2) {
+            cards.push((Side::Banker, c));
+        }
+        HandDetail {
+            cards,
+            player: SideDetail {
+                initial: b.player_initial_points(),
+                total: b.player_points(),
+                drew: b.player_total_cards() == 3,
+                natural: b.player_natural(),
+            },
+            banker: SideDetail {
+                initial: b.banker_initial_points(),
+                total: b.banker_points(),
+                drew: b.banker_total_cards() == 3,
+                natural: b.banker_natural(),
+            },
+            result: b.result(),
+            side_bets: vec![],
+            pushed_side_bets: vec![],
+        }
+    }
+
+    // None until the dealer is done with the hand
+    pub fn from_dealer(d: BaccaratDealer) -> Option<HandDetail> {
+        Baccarat::from_dealer(d, &vec![]).map(|b| HandDetail::new(&b))
+    }
+
+    // records the bets of the payout map which are not among the game's main bets
+    pub fn with_side_bets<B: BetSerde + Hash + Eq>(mut self, payouts: &HashMap<B, f64>, main: &[B]) -> HandDetail {
+        let mut won = vec![];
+        let mut pushed = vec![];
+        for (b, &r) in payouts {
+            if main.contains(b) {
+                continue;
+            }
+            if r > 1.0 {
+                won.push(b.to_u16());
+            } else if r == 1.0 {
+                pushed.push(b.to_u16());
+            }
+        }
+        won.sort();
+        pushed.sort();
+        self.side_bets = won;
+        self.pushed_side_bets = pushed;
+        self
+    }
+
+    pub fn card_strs(&self) -> Vec<String> {
+        self.cards.iter().map(|&(_, c)| card_to_str(c)).collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        let cards: Vec<String> = self.cards
+            .iter()
+            .map(|&(side, c)| format!("{{\"side\":\"{}\",\"card\":\"{}\"}}", side_str(side), card_to_str(c)))
+            .collect();
+        let ids = |v: &[u16]| v.iter().map(|id| id.to_string()).collect::<Vec<String>>().join(",");
+        let (winner, points) = match self.result {
+            Result::Player(n) => ("player", n),
+            Result::Banker(n) => ("banker", n),
+            Result::Tie(n) => ("tie", n),
+        };
+        format!(
+            "{{\"cards\":[{}],\"player\":{},\"banker\":{},\"winner\":\"{}\",\"points\":{},\"side_bets\":[{}],\"pushed_side_bets\":[{}]}}",
+            cards.join(","),
+            side_json(&self.player),
+            side_json(&self.banker),
+            winner,
+            points,
+            ids(&self.side_bets),
+            ids(&self.pushed_side_bets)
+        )
+    }
+}
+
+fn side_str(side: Side) -> &'static str {
+    match side {
+        Side::Player => "player",
+        Side::Banker => "banker",
+    }
+}
+
+fn side_json(s: &SideDetail) -> String {
+    format!(
+        "{{\"initial\":{},\"total\":{},\"drew\":{},\"natural\":{}}}",
+        s.initial, s.total, s.drew, s.natural
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::baccarat::commission_baccarat;
+    use games::card::baccarat::dealer::init_baccarat_dealer;
+    use games::card::serde::str_to_card;
+
+    fn card(s: &str) -> Card {
+        str_to_card(s).unwrap()
+    }
+
+    fn hand(cards: &[&str]) -> Baccarat {
+        let cards: Vec<Card> = cards.iter().map(|c| card(c)).collect();
+        Baccarat::from(&cards).unwrap()
+    }
+
+    fn deal(cards: &[&str]) -> BaccaratDealer {
+        let mut d = init_baccarat_dealer();
+        for c in cards {
+            assert!(d.deal(card(c)));
+        }
+        d
+    }
+
+    #[test]
+    fn test_natural() {
+        let d = deal(&["ST", "S9", "H2", "DQ"]);
+        let h = HandDetail::from_dealer(d).unwrap();
+        assert_eq!(vec!["ST", "S9", "H2", "DQ"], h.card_strs());
+        assert_eq!(Side::Player, h.cards[0].0);
+        assert_eq!(Side::Banker, h.cards[1].0);
+        assert_eq!(
+            SideDetail {
+                initial: 9,
+                total: 9,
+                drew: false,
+                natural: true,
+            },
+            h.banker
+        );
+        assert!(!h.player.natural && !h.player.drew);
+        assert_eq!(Result::Banker(9), h.result);
+        assert_eq!(
+            "{\"cards\":[{\"side\":\"player\",\"card\":\"ST\"},{\"side\":\"banker\",\"card\":\"S9\"},{\"side\":\"player\",\"card\":\"H2\"},{\"side\":\"banker\",\"card\":\"DQ\"}],\"player\":{\"initial\":2,\"total\":2,\"drew\":false,\"natural\":false},\"banker\":{\"initial\":9,\"total\":9,\"drew\":false,\"natural\":true},\"winner\":\"banker\",\"points\":9,\"side_bets\":[],\"pushed_side_bets\":[]}",
+            h.to_json()
+        );
+    }
+
+    #[test]
+    fn test_third_cards() {
+        let cards = ["D7", "D5", "D7", "H6", "CT", "S6"];
+        let d = deal(&cards);
+        let h = HandDetail::from_dealer(d).unwrap();
+        assert_eq!(cards.to_vec(), h.card_strs());
+        assert_eq!(Side::Player, h.cards[4].0);
+        assert_eq!(Side::Banker, h.cards[5].0);
+        assert_eq!((4, 4, true), (h.player.initial, h.player.total, h.player.drew));
+        assert_eq!((1, 7, true), (h.banker.initial, h.banker.total, h.banker.drew));
+        assert_eq!(Result::Banker(7), h.result);
+    }
+
+    #[test]
+    fn test_side_bets() {
+        use games::card::baccarat::commission_baccarat::MAIN_BETS;
+
+        let b = hand(&["H7", "D5", "S7", "C4"]);
+        let h = HandDetail::new(&b).with_side_bets(&commission_baccarat::payout_map(&b), &MAIN_BETS);
+        // player pair, banker natural 9, small and the banker Dragon Bonus on a natural win
+        assert_eq!(h.side_bets, vec![5, 7, 12, 13]);
+        assert!(h.pushed_side_bets.is_empty());
+        assert!(h.to_json().ends_with("\"side_bets\":[5,7,12,13],\"pushed_side_bets\":[]}"));
+
+        // natural tie on 9 pushes both Dragon Bonus bets
+        let b = hand(&["S9", "H9", "ST", "DK"]);
+        let h = HandDetail::new(&b).with_side_bets(&commission_baccarat::payout_map(&b), &MAIN_BETS);
+        assert_eq!(h.side_bets, vec![12]);
+        assert_eq!(h.pushed_side_bets, vec![13, 14]);
+    }
+
+    #[test]
+    fn test_matches_settlement() {
+        let b = hand(&["D7", "D5", "D7", "H6", "CT", "S6"]);
+        let h = HandDetail::new(&b);
+        assert_eq!(h.result, b.result());
+        assert_eq!((h.player.total, h.banker.total), (b.player_points(), b.banker_points()));
+        assert_eq!(h, HandDetail::from_dealer(deal(&["D7", "D5", "D7", "H6", "CT", "S6"])).unwrap());
+    }
+
+    #[test]
+    fn test_unfinished() {
+        let d = deal(&["ST", "S9", "H2"]);
+        assert!(HandDetail::from_dealer(d).is_none());
+    }
+}
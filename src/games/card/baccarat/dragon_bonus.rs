@@ -0,0 +1,83 @@
+use super::common::Baccarat;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Side {
+    Banker,
+    Player,
+}
+
+// Dragon Bonus on one side: a natural win pays 1:1 and a natural tie pushes,
+// otherwise the side has to win by 4 points or more.
+pub fn payout(b: &Baccarat, side: Side) -> Option<f64> {
+    let (own, other, natural, other_natural) = match side {
+        Side::Banker => (b.banker_points(), b.player_points(), b.banker_natural(), b.player_natural()),
+        Side::Player => (b.player_points(), b.banker_points(), b.player_natural(), b.banker_natural()),
+    };
+    if natural && other_natural && own == other {
+        return Some(1.0);
+    }
+    if own <= other {
+        return None;
+    }
+    if natural {
+        return Some(2.0);
+    }
+    match own - other {
+        9 => Some(31.0),
+        8 => Some(11.0),
+        7 => Some(7.0),
+        6 => Some(5.0),
+        5 => Some(3.0),
+        4 => Some(2.0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::serde::str_to_card;
+    use games::card::Card;
+
+    fn hand(cards: &[&str]) -> Baccarat {
+        let cards: Vec<Card> = cards.iter().map(|s| str_to_card(s).unwrap()).collect();
+        Baccarat::from(&cards).unwrap()
+    }
+
+    #[test]
+    fn test_natural() {
+        // player natural 9 against banker natural 8
+        let b = hand(&["S9", "S8", "ST", "HT"]);
+        assert_eq!(payout(&b, Side::Player), Some(2.0));
+        assert_eq!(payout(&b, Side::Banker), None);
+        // natural tie
+        let b = hand(&["S8", "H8", "ST", "HK"]);
+        assert_eq!(payout(&b, Side::Player), Some(1.0));
+        assert_eq!(payout(&b, Side::Banker), Some(1.0));
+    }
+
+    #[test]
+    fn test_margin() {
+        // player T 2 3 = 5, banker 4 T 3 = 7, win by 2
+        let b = hand(&["ST", "S4", "S2", "HT", "S3", "D3"]);
+        assert_eq!(payout(&b, Side::Banker), None);
+        assert_eq!(payout(&b, Side::Player), None);
+        // player 6 T = 6 stands, banker T K draws 2 = 2, win by 4
+        let b = hand(&["S6", "ST", "HT", "SK", "S2"]);
+        assert_eq!(b.player_points(), 6);
+        assert_eq!(b.banker_points(), 2);
+        assert_eq!(payout(&b, Side::Player), Some(2.0));
+        // player T K 9 = 9, banker T K Q = 0, win by 9
+        let b = hand(&["ST", "HT", "SK", "HK", "S9", "SQ"]);
+        assert_eq!((b.player_points(), b.banker_points()), (9, 0));
+        assert_eq!(payout(&b, Side::Player), Some(31.0));
+        // player 7 T = 7 stands, banker T K A = 1, win by 6
+        let b = hand(&["S7", "ST", "HT", "SK", "SA"]);
+        assert_eq!((b.player_points(), b.banker_points()), (7, 1));
+        assert_eq!(payout(&b, Side::Player), Some(5.0));
+        // a non natural tie loses
+        let b = hand(&["S7", "S7", "ST", "HT"]);
+        assert_eq!(payout(&b, Side::Player), None);
+        assert_eq!(payout(&b, Side::Banker), None);
+    }
+}
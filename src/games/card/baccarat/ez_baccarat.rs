@@ -0,0 +1,248 @@
+use std::collections::{HashMap, HashSet};
+use super::common::{Baccarat, Result};
+use super::pairs::{PairBet, PairConfig, PairPaytable};
+use games::{BetSerde, Game};
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+pub enum Bets {
+    Banker,
+    Player,
+    Tie,
+    BankerPair,
+    PlayerPair,
+    Dragon7,
+    Panda8,
+    PerfectPair,
+    EitherPair,
+}
+
+use self::Bets::*;
+
+impl BetSerde for Bets {
+    fn to_u16(&self) -> u16 {
+        match *self {
+            Banker => 1,
+            Player => 2,
+            Tie => 3,
+            BankerPair => 4,
+            PlayerPair => 5,
+            Dragon7 => 6,
+            Panda8 => 7,
+            PerfectPair => 8,
+            EitherPair => 9,
+        }
+    }
+
+    fn from_u16(id: u16) -> Option<Bets> {
+        match id {
+            1 => Some(Banker),
+            2 => Some(Player),
+            3 => Some(Tie),
+            4 => Some(BankerPair),
+            5 => Some(PlayerPair),
+            6 => Some(Dragon7),
+            7 => Some(Panda8),
+            8 => Some(PerfectPair),
+            9 => Some(EitherPair),
+            _ => None,
+        }
+    }
+}
+
+lazy_static! {
+    static ref ALL_BETS:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerPair, PlayerPair, Dragon7, Panda8, PerfectPair, EitherPair };
+    static ref BETS_AFTER70:HashSet<Bets> = hashset!{ Banker, Player, Tie, Dragon7, Panda8 };
+}
+
+pub const MAIN_BETS: [Bets; 3] = [Banker, Player, Tie];
+
+pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
+    if hands <= 70 {
+        &ALL_BETS
+    } else {
+        &BETS_AFTER70
+    }
+}
+
+pub struct EzBaccaratGame;
+
+impl Game for EzBaccaratGame {
+    type B = Bets;
+
+    fn name(&self) -> &'static str {
+        "ez_baccarat"
+    }
+
+    fn main_bets(&self) -> &[Bets] {
+        &MAIN_BETS
+    }
+}
+
+// any pair has no spot on this layout
+pub const PAIR_BETS: [PairBet; 4] = [
+    PairBet::BankerPair,
+    PairBet::PlayerPair,
+    PairBet::PerfectPair,
+    PairBet::EitherPair,
+];
+
+// all of the pair bets above at the default paytable
+pub fn pair_config() -> PairConfig {
+    PairConfig::new(PairPaytable::default(), PAIR_BETS.to_vec())
+}
+
+pub fn payout_map(b: &Baccarat) -> HashMap<Bets, f64> {
+    pair_payout_map(b, &pair_config())
+}
+
+// None when the config has a pair bet outside PAIR_BETS
+pub fn payout_map_with(b: &Baccarat, pairs: &PairConfig) -> Option<HashMap<Bets, f64>> {
+    if !pairs.validate(&PAIR_BETS) {
+        return None;
+    }
+    Some(pair_payout_map(b, pairs))
+}
+
+fn pair_payout_map(b: &Baccarat, pairs: &PairConfig) -> HashMap<Bets, f64> {
+    let result = b.result();
+    let mut map = result_payout_map(b, result);
+    side_bet(b, pairs, &mut map);
+    map
+}
+
+fn pair_spot(bet: PairBet) -> Option<Bets> {
+    match bet {
+        PairBet::BankerPair => Some(BankerPair),
+        PairBet::PlayerPair => Some(PlayerPair),
+        PairBet::PerfectPair => Some(PerfectPair),
+        PairBet::EitherPair => Some(EitherPair),
+        PairBet::AnyPair => None,
+    }
+}
+
+#[inline]
+fn side_bet(b: &Baccarat, pairs: &PairConfig, map: &mut HashMap<Bets, f64>) {
+    for (bet, r) in pairs.payouts(b) {
+        if let Some(spot) = pair_spot(bet) {
+            map.insert(spot, r);
+        }
+    }
+}
+
+// no commission, a banker win with a three card 7 pushes and pays the Dragon 7,
+// a player win with a three card 8 pays the Panda 8.
+#[inline]
+fn result_payout_map(b: &Baccarat, result: Result) -> HashMap<Bets, f64> {
+    let mut map = HashMap::<Bets, f64>::new();
+    match result {
+        Result::Tie(_) => {
+            map.insert(Banker, 1.0);
+            map.insert(Player, 1.0);
+            map.insert(Tie, 9.0);
+        }
+        Result::Player(t) => {
+            if t == 8 && b.player_total_cards() == 3 {
+                map.insert(Panda8, 26.0);
+            }
+            map.insert(Player, 2.0);
+        }
+        Result::Banker(t) => {
+            if t == 7 && b.banker_total_cards() == 3 {
+                map.insert(Dragon7, 41.0);
+                map.insert(Banker, 1.0);
+            } else {
+                map.insert(Banker, 2.0);
+            }
+        }
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::serde::str_to_card;
+    use games::card::Card;
+
+    fn hand(cards: &[&str]) -> Baccarat {
+        let cards: Vec<Card> = cards.iter().map(|s| str_to_card(s).unwrap()).collect();
+        Baccarat::from(&cards).unwrap()
+    }
+
+    #[test]
+    fn test_valid_bets() {
+        assert_eq!(9, valid_bets(1).len());
+        assert_eq!(9, valid_bets(70).len());
+        assert_eq!(5, valid_bets(71).len());
+    }
+
+    #[test]
+    fn test_dragon7() {
+        // player T 2 3 = 5, banker 4 T 3 = 7
+        let b = hand(&["ST", "S4", "S2", "HT", "S3", "D3"]);
+        assert_eq!(b.result(), Result::Banker(7));
+        assert_eq!(payout_map(&b), hashmap!{Banker => 1.0, Dragon7 => 41.0});
+    }
+
+    #[test]
+    fn test_banker_two_card_7() {
+        // player 6 T = 6, banker 7 T = 7
+        let b = hand(&["S6", "S7", "ST", "HT"]);
+        assert_eq!(payout_map(&b), hashmap!{Banker => 2.0});
+    }
+
+    #[test]
+    fn test_panda8() {
+        // player T 3 5 = 8, banker 5 T K = 5
+        let b = hand(&["ST", "S5", "S3", "HT", "S5", "SK"]);
+        assert_eq!(b.result(), Result::Player(8));
+        assert_eq!(payout_map(&b), hashmap!{Player => 2.0, Panda8 => 26.0});
+    }
+
+    #[test]
+    fn test_player_two_card_8() {
+        // natural 8 against banker 5 T = 5
+        let b = hand(&["S8", "S5", "ST", "HT"]);
+        assert_eq!(payout_map(&b), hashmap!{Player => 2.0});
+    }
+
+    #[test]
+    fn test_tie() {
+        let b = hand(&["S9", "H9", "ST", "DK"]);
+        assert_eq!(payout_map(&b), hashmap!{Banker => 1.0, Player => 1.0, Tie => 9.0});
+    }
+
+    #[test]
+    fn test_pairs() {
+        // player S9 S9, banker DK HT: perfect pair on the player
+        let b = hand(&["S9", "DK", "S9", "HT"]);
+        assert_eq!(
+            payout_map(&b),
+            hashmap!{Player => 2.0, PlayerPair => 12.0, PerfectPair => 26.0, EitherPair => 6.0}
+        );
+        assert_eq!(PerfectPair.to_u16(), 8);
+        assert_eq!(Bets::from_u16(9), Some(EitherPair));
+
+        // player S9 H9, banker D4 C4: unsuited pairs on both sides, tie on 8
+        let b = hand(&["S9", "D4", "H9", "C4"]);
+        assert_eq!(
+            payout_map(&b),
+            hashmap!{Banker => 1.0, Player => 1.0, Tie => 9.0, PlayerPair => 12.0, BankerPair => 12.0, EitherPair => 6.0}
+        );
+
+        let t = PairPaytable {
+            perfect_pair: 31.0,
+            either_pair: 5.0,
+            ..PairPaytable::default()
+        };
+        let c = PairConfig::new(t, vec![PairBet::PerfectPair, PairBet::EitherPair]);
+        let b = hand(&["S9", "DK", "S9", "HT"]);
+        assert_eq!(
+            payout_map_with(&b, &c),
+            Some(hashmap!{Player => 2.0, PerfectPair => 31.0, EitherPair => 5.0})
+        );
+        // any pair has no spot, the config is refused rather than the bet dropped
+        let c = PairConfig::new(t, vec![PairBet::PerfectPair, PairBet::AnyPair]);
+        assert_eq!(payout_map_with(&b, &c), None);
+    }
+}
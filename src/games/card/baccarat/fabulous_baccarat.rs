@@ -48,6 +48,8 @@ lazy_static! {
     static ref BETS_AFTER70:HashSet<Bets> = hashset!{ Banker, Player, Tie, BankerF4, PlayerF4};
 }
 
+pub const MAIN_BETS: [Bets; 3] = [Banker, Player, Tie];
+
 pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
     if hands <= 70 {
         &ALL_BETS
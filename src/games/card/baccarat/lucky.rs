@@ -0,0 +1,116 @@
+use super::common::{Baccarat, Result};
+
+// side bets on a winning 6 or 7, paid by the number of cards dealt
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LuckyBet {
+    // Super 6 / Lucky 6, counted on the banker cards
+    BankerWins6,
+    // any result with a total of 6, ties included, counted on all cards
+    Total6,
+    // Lucky 7, counted on the player cards
+    PlayerWins7,
+    // Super Lucky 7, player 7 against banker 6, counted on all cards
+    PlayerWins7Over6,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Paytable {
+    Flat(f64),
+    ByCards(&'static [(usize, f64)]),
+}
+
+impl Paytable {
+    pub fn ratio(&self, cards: usize) -> Option<f64> {
+        match *self {
+            Paytable::Flat(r) => Some(r),
+            Paytable::ByCards(t) => t.iter().find(|&&(n, _)| n == cards).map(|&(_, r)| r),
+        }
+    }
+}
+
+pub const SUPER6: Paytable = Paytable::ByCards(&[(2, 13.0), (3, 19.0)]);
+pub const LUCKY6_ANY_RESULT: Paytable = Paytable::Flat(7.0);
+pub const LUCKY7: Paytable = Paytable::ByCards(&[(2, 7.0), (3, 16.0)]);
+pub const SUPER_LUCKY7: Paytable = Paytable::ByCards(&[(4, 31.0), (5, 41.0), (6, 101.0)]);
+
+// banker points are only needed by Super Lucky 7
+fn hit(bet: LuckyBet, result: Result, banker_points: Option<u8>) -> bool {
+    match bet {
+        LuckyBet::BankerWins6 => result == Result::Banker(6),
+        LuckyBet::Total6 => result.total_points() == 6,
+        LuckyBet::PlayerWins7 => result == Result::Player(7),
+        LuckyBet::PlayerWins7Over6 => result == Result::Player(7) && banker_points == Some(6),
+    }
+}
+
+pub fn payout(b: &Baccarat, bet: LuckyBet, t: &Paytable) -> Option<f64> {
+    if !hit(bet, b.result(), Some(b.banker_points())) {
+        return None;
+    }
+    let cards = match bet {
+        LuckyBet::BankerWins6 => b.banker_total_cards(),
+        LuckyBet::PlayerWins7 => b.player_total_cards(),
+        LuckyBet::Total6 | LuckyBet::PlayerWins7Over6 => b.total_cards(),
+    };
+    t.ratio(cards)
+}
+
+// for flat paytables of bets decided by the result alone
+pub fn result_payout(result: Result, bet: LuckyBet, t: &Paytable) -> Option<f64> {
+    match *t {
+        Paytable::Flat(r) if hit(bet, result, None) => Some(r),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::serde::str_to_card;
+    use games::card::Card;
+
+    fn hand(cards: &[&str]) -> Baccarat {
+        let cards: Vec<Card> = cards.iter().map(|s| str_to_card(s).unwrap()).collect();
+        Baccarat::from(&cards).unwrap()
+    }
+
+    #[test]
+    fn test_super6() {
+        // player T 2 T = 2, banker 6 T = 6 stands on the player T
+        let b = hand(&["ST", "S6", "S2", "HT", "DT"]);
+        assert_eq!(payout(&b, LuckyBet::BankerWins6, &SUPER6), Some(13.0));
+        assert_eq!(payout(&b, LuckyBet::Total6, &LUCKY6_ANY_RESULT), Some(7.0));
+        // player T 2 3 = 5, banker 3 T 3 = 6
+        let b = hand(&["ST", "S3", "S2", "HT", "S3", "D3"]);
+        assert_eq!(b.result(), Result::Banker(6));
+        assert_eq!(payout(&b, LuckyBet::BankerWins6, &SUPER6), Some(19.0));
+        assert_eq!(payout(&b, LuckyBet::PlayerWins7, &LUCKY7), None);
+    }
+
+    #[test]
+    fn test_lucky7() {
+        // player 7 T = 7, banker 6 T = 6
+        let b = hand(&["S7", "S6", "ST", "HT"]);
+        assert_eq!(payout(&b, LuckyBet::PlayerWins7, &LUCKY7), Some(7.0));
+        assert_eq!(payout(&b, LuckyBet::PlayerWins7Over6, &SUPER_LUCKY7), Some(31.0));
+        assert_eq!(payout(&b, LuckyBet::BankerWins6, &SUPER6), None);
+        // player T 2 5 = 7, banker T 6 = 6 stands on the player 5
+        let b = hand(&["ST", "S6", "S2", "HT", "S5"]);
+        assert_eq!(b.result(), Result::Player(7));
+        assert_eq!(payout(&b, LuckyBet::PlayerWins7, &LUCKY7), Some(16.0));
+        assert_eq!(payout(&b, LuckyBet::PlayerWins7Over6, &SUPER_LUCKY7), Some(41.0));
+        // player 7 T = 7, banker 2 T 4 = 6
+        let b = hand(&["S7", "S2", "ST", "HT", "S4"]);
+        assert_eq!(payout(&b, LuckyBet::PlayerWins7Over6, &SUPER_LUCKY7), Some(41.0));
+        // player 7 T = 7, banker 5 T K = 5
+        let b = hand(&["S7", "S5", "ST", "HT", "SK"]);
+        assert_eq!(payout(&b, LuckyBet::PlayerWins7Over6, &SUPER_LUCKY7), None);
+    }
+
+    #[test]
+    fn test_result_payout() {
+        assert_eq!(result_payout(Result::Tie(6), LuckyBet::Total6, &LUCKY6_ANY_RESULT), Some(7.0));
+        assert_eq!(result_payout(Result::Player(5), LuckyBet::Total6, &LUCKY6_ANY_RESULT), None);
+        assert_eq!(result_payout(Result::Banker(6), LuckyBet::BankerWins6, &SUPER6), None);
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use super::common::{Baccarat, Result};
+use super::lucky::{self, LuckyBet, LUCKY6_ANY_RESULT};
 use games::card::{Card, Rank};
 use games::BetSerde;
 
@@ -99,6 +100,8 @@ lazy_static! {
 
 pub struct LuckyBaccaratGame;
 
+pub const MAIN_BETS: [Bets; 3] = [Banker, Player, Tie];
+
 pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
     if hands <= 40 {
         &ALL_BETS
@@ -142,8 +145,8 @@ fn side_bet(pair: (Card, Card), b1: Bets, b2: Bets, b3: Bets, map: &mut HashMap<
 
 fn result_payout_map(result: Result) -> HashMap<Bets, f64> {
     let mut map = HashMap::<Bets, f64>::new();
-    if result.total_points() == 6 {
-        map.insert(Lucky6, 7.0);
+    if let Some(r) = lucky::result_payout(result, LuckyBet::Total6, &LUCKY6_ANY_RESULT) {
+        map.insert(Lucky6, r);
     }
     match result {
         Result::Tie(_) => {
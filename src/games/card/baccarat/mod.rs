@@ -23,9 +23,16 @@ fn total_points(cards: &Vec<Card>) -> u8 {
 }
 
 pub mod dealer;
+pub mod detail;
 pub mod common;
 pub mod commission_baccarat;
 pub mod noncommission_baccarat;
 pub mod fabulous_baccarat;
 pub mod lucky_baccarat;
 pub mod sevenup_baccarat;
+pub mod ez_baccarat;
+pub mod dragon_bonus;
+pub mod pairs;
+pub mod lucky;
+pub mod squeeze;
+pub mod tiger_baccarat;
@@ -0,0 +1,189 @@
+use super::common::Baccarat;
+use games::card::Card;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PairBet {
+    // first two cards of the side are a pair
+    BankerPair,
+    PlayerPair,
+    // a suited pair on either side, more when both sides have one
+    PerfectPair,
+    // a pair on either side
+    EitherPair,
+    // any two of the first four cards make a pair, across the sides too
+    AnyPair,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PairPaytable {
+    pub pair: f64,
+    pub perfect_pair: f64,
+    pub perfect_pair_both: f64,
+    pub either_pair: f64,
+    pub any_pair: f64,
+    pub any_pair_suited: f64,
+}
+
+impl Default for PairPaytable {
+    fn default() -> PairPaytable {
+        PairPaytable {
+            pair: 12.0,
+            perfect_pair: 26.0,
+            perfect_pair_both: 201.0,
+            either_pair: 6.0,
+            any_pair: 2.5,
+            any_pair_suited: 26.0,
+        }
+    }
+}
+
+// the pair bets a variant offers and what they pay
+#[derive(Clone, PartialEq, Debug)]
+pub struct PairConfig {
+    pub paytable: PairPaytable,
+    pub bets: Vec<PairBet>,
+}
+
+impl PairConfig {
+    pub fn new(paytable: PairPaytable, bets: Vec<PairBet>) -> PairConfig {
+        PairConfig { paytable, bets }
+    }
+
+    // false when the config asks for a pair bet the layout does not offer
+    pub fn validate(&self, offered: &[PairBet]) -> bool {
+        self.bets.iter().all(|b| offered.contains(b))
+    }
+
+    // winning pair bets of the hand with their payouts
+    pub fn payouts(&self, b: &Baccarat) -> Vec<(PairBet, f64)> {
+        self.bets
+            .iter()
+            .filter_map(|&bet| payout(b, bet, &self.paytable).map(|r| (bet, r)))
+            .collect()
+    }
+}
+
+// banker and player pair at the default paytable
+impl Default for PairConfig {
+    fn default() -> PairConfig {
+        PairConfig::new(
+            PairPaytable::default(),
+            vec![PairBet::BankerPair, PairBet::PlayerPair],
+        )
+    }
+}
+
+fn is_pair(&(c1, c2): &(Card, Card)) -> bool {
+    c1.is_same_rank(&c2)
+}
+
+fn is_suited_pair(&(c1, c2): &(Card, Card)) -> bool {
+    c1.is_same_rank(&c2) && c1.is_same_suit(&c2)
+}
+
+pub fn payout(b: &Baccarat, bet: PairBet, t: &PairPaytable) -> Option<f64> {
+    let banker = b.banker_first2();
+    let player = b.player_first2();
+    let r = match bet {
+        PairBet::BankerPair if is_pair(&banker) => t.pair,
+        PairBet::PlayerPair if is_pair(&player) => t.pair,
+        PairBet::PerfectPair => match (is_suited_pair(&banker), is_suited_pair(&player)) {
+            (true, true) => t.perfect_pair_both,
+            (true, false) | (false, true) => t.perfect_pair,
+            _ => return None,
+        },
+        PairBet::EitherPair if is_pair(&banker) || is_pair(&player) => t.either_pair,
+        PairBet::AnyPair => {
+            let cards = [banker.0, banker.1, player.0, player.1];
+            let mut best = None;
+            for i in 0..4 {
+                for j in (i + 1)..4 {
+                    let p = (cards[i], cards[j]);
+                    if is_suited_pair(&p) {
+                        best = Some(t.any_pair_suited);
+                    } else if is_pair(&p) && best.is_none() {
+                        best = Some(t.any_pair);
+                    }
+                }
+            }
+            return best;
+        }
+        _ => return None,
+    };
+    Some(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::serde::str_to_card;
+
+    fn hand(cards: &[&str]) -> Baccarat {
+        let cards: Vec<Card> = cards.iter().map(|s| str_to_card(s).unwrap()).collect();
+        Baccarat::from(&cards).unwrap()
+    }
+
+    #[test]
+    fn test_pairs() {
+        let t = PairPaytable::default();
+        // player S9 H9, banker DK DT
+        let b = hand(&["S9", "DK", "H9", "DT"]);
+        assert_eq!(payout(&b, PairBet::PlayerPair, &t), Some(12.0));
+        assert_eq!(payout(&b, PairBet::BankerPair, &t), None);
+        assert_eq!(payout(&b, PairBet::EitherPair, &t), Some(6.0));
+        assert_eq!(payout(&b, PairBet::PerfectPair, &t), None);
+        assert_eq!(payout(&b, PairBet::AnyPair, &t), Some(2.5));
+    }
+
+    #[test]
+    fn test_validate() {
+        let offered = [PairBet::BankerPair, PairBet::PlayerPair];
+        assert!(PairConfig::default().validate(&offered));
+        let c = PairConfig::new(PairPaytable::default(), vec![PairBet::PlayerPair, PairBet::AnyPair]);
+        assert!(!c.validate(&offered));
+        assert!(c.validate(&[PairBet::PlayerPair, PairBet::AnyPair]));
+    }
+
+    #[test]
+    fn test_perfect_pair() {
+        let t = PairPaytable::default();
+        // player S9 S9, banker DK HT
+        let b = hand(&["S9", "DK", "S9", "HT"]);
+        assert_eq!(payout(&b, PairBet::PerfectPair, &t), Some(26.0));
+        assert_eq!(payout(&b, PairBet::AnyPair, &t), Some(26.0));
+        // both sides suited pairs
+        let b = hand(&["S9", "D4", "S9", "D4"]);
+        assert_eq!(payout(&b, PairBet::PerfectPair, &t), Some(201.0));
+        assert_eq!(payout(&b, PairBet::EitherPair, &t), Some(6.0));
+    }
+
+    #[test]
+    fn test_config() {
+        let b = hand(&["S9", "D4", "S9", "D4"]);
+        assert_eq!(
+            PairConfig::default().payouts(&b),
+            vec![(PairBet::BankerPair, 12.0), (PairBet::PlayerPair, 12.0)]
+        );
+        let t = PairPaytable {
+            perfect_pair_both: 251.0,
+            ..PairPaytable::default()
+        };
+        let c = PairConfig::new(t, vec![PairBet::PerfectPair, PairBet::AnyPair]);
+        assert_eq!(c.payouts(&b), vec![(PairBet::PerfectPair, 251.0), (PairBet::AnyPair, 26.0)]);
+        assert!(c.payouts(&hand(&["S9", "D8", "HK", "CQ"])).is_empty());
+    }
+
+    #[test]
+    fn test_any_pair_across_sides() {
+        let t = PairPaytable {
+            any_pair: 3.0,
+            ..PairPaytable::default()
+        };
+        // player S9 HK, banker D9 CQ
+        let b = hand(&["S9", "D9", "HK", "CQ"]);
+        assert_eq!(payout(&b, PairBet::EitherPair, &t), None);
+        assert_eq!(payout(&b, PairBet::AnyPair, &t), Some(3.0));
+        let b = hand(&["S9", "D8", "HK", "CQ"]);
+        assert_eq!(payout(&b, PairBet::AnyPair, &t), None);
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use super::common::{Baccarat, Result};
+use super::lucky::Paytable;
 use games::BetSerde;
 
 #[derive(Hash, PartialEq, Eq, Debug)]
@@ -40,6 +41,8 @@ lazy_static! {
 
 pub struct SevenupBaccaratGame;
 
+pub const MAIN_BETS: [Bets; 3] = [Banker, Player, Tie];
+
 pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
     if hands <= 40 {
         &ALL_BETS
@@ -48,31 +51,66 @@ pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
     }
 }
 
+// Super 7 pays by the number of sevens dealt, the seed card included
+pub const SUPER7: Paytable =
+    Paytable::ByCards(&[(2, 2.5), (3, 7.0), (4, 16.0), (5, 78.0), (6, 778.0)]);
+pub const SUPER7_ENHANCED: Paytable =
+    Paytable::ByCards(&[(2, 3.0), (3, 9.0), (4, 21.0), (5, 101.0), (6, 1001.0)]);
+pub const SUPER7_HIGH_ONLY: Paytable =
+    Paytable::ByCards(&[(3, 11.0), (4, 26.0), (5, 151.0), (6, 2001.0)]);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SevenupConfig {
+    // banker winning with 7
+    pub banker7: f64,
+    // player winning with 7
+    pub player7: f64,
+    pub tie: f64,
+    pub tie7: f64,
+    pub super7: Paytable,
+}
+
+impl Default for SevenupConfig {
+    fn default() -> SevenupConfig {
+        SevenupConfig {
+            banker7: 2.5,
+            player7: 1.5,
+            tie: 8.0,
+            tie7: 10.0,
+            super7: SUPER7,
+        }
+    }
+}
+
 pub fn payout_map(b: &Baccarat) -> HashMap<Bets, f64> {
-    let mut map = result_payout_map(b.result());
-    if let Some(r) = ratio7(b.count_cards(7)) {
+    payout_map_with(b, &SevenupConfig::default())
+}
+
+pub fn payout_map_with(b: &Baccarat, config: &SevenupConfig) -> HashMap<Bets, f64> {
+    let mut map = result_payout_map(b.result(), config);
+    if let Some(r) = config.super7.ratio(b.count_cards(7)) {
         map.insert(Super7, r);
     }
     map
 }
 
-fn result_payout_map(result: Result) -> HashMap<Bets, f64> {
+fn result_payout_map(result: Result, config: &SevenupConfig) -> HashMap<Bets, f64> {
     let mut map = HashMap::<Bets, f64>::new();
     match result {
         Result::Tie(7) => {
-            map.insert(Tie, 10.0);
+            map.insert(Tie, config.tie7);
         }
         Result::Tie(_) => {
-            map.insert(Tie, 8.0);
+            map.insert(Tie, config.tie);
         }
         Result::Banker(7) => {
-            map.insert(Banker, 2.5);
+            map.insert(Banker, config.banker7);
         }
         Result::Banker(_) => {
             map.insert(Banker, 2.0);
         }
         Result::Player(7) => {
-            map.insert(Player, 1.5);
+            map.insert(Player, config.player7);
         }
         Result::Player(_) => {
             map.insert(Player, 2.0);
@@ -85,22 +123,12 @@ fn result_payout_map(result: Result) -> HashMap<Bets, f64> {
     map
 }
 
-fn ratio7(n7: usize) -> Option<f64> {
-    match n7 {
-        6 => Some(778.0),
-        5 => Some(78.0),
-        4 => Some(16.0),
-        3 => Some(7.0),
-        2 => Some(2.5),
-        _ => None,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use games::card::serde::str_to_card;
-    use games::card::Card;
+    use games::card::baccarat::dealer::seed_from_shoe;
+    use games::card::{new_shoe, Card};
 
     fn card(s: &str) -> Card {
         str_to_card(s).unwrap()
@@ -192,12 +220,129 @@ mod tests {
 
     #[test]
     fn test_ratio7() {
-        assert_eq!(Some(778.0), ratio7(6));
-        assert_eq!(Some(78.0), ratio7(5));
-        assert_eq!(Some(16.0), ratio7(4));
-        assert_eq!(Some(7.0), ratio7(3));
-        assert_eq!(Some(2.5), ratio7(2));
-        assert_eq!(None, ratio7(1));
+        assert_eq!(Some(778.0), SUPER7.ratio(6));
+        assert_eq!(Some(78.0), SUPER7.ratio(5));
+        assert_eq!(Some(16.0), SUPER7.ratio(4));
+        assert_eq!(Some(7.0), SUPER7.ratio(3));
+        assert_eq!(Some(2.5), SUPER7.ratio(2));
+        assert_eq!(None, SUPER7.ratio(1));
+        assert_eq!(None, SUPER7.ratio(0));
+        assert_eq!(None, SUPER7.ratio(7));
+    }
+
+    #[test]
+    fn test_super7_rungs() {
+        let cards = vec![
+            card("D7"),
+            card("D5"),
+            card("D7"),
+            card("H6"),
+            card("CT"),
+            card("S6"),
+        ];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(2, b.count_cards(7));
+        assert_eq!(payout_map(&b), hashmap!{Banker=>2.5, Super7=>2.5});
+
+        let cards = vec![
+            card("D7"),
+            card("H4"),
+            card("H7"),
+            card("D7"),
+            card("S6"),
+            card("HK"),
+        ];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(3, b.count_cards(7));
+        assert_eq!(payout_map(&b), hashmap!{Banker=>2.0, Super7=>7.0});
+
+        // P D7 H7 C7, B S7 C7 H7: tie on 1 with six sevens
+        let cards = vec![
+            card("D7"),
+            card("S7"),
+            card("H7"),
+            card("C7"),
+            card("C7"),
+            card("H7"),
+        ];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(6, b.count_cards(7));
+        assert_eq!(Result::Tie(1), b.result());
+        let m = payout_map(&b);
+        assert_eq!(m, hashmap!{Banker=>1.0, Player=>1.0, Tie=>8.0, Super7=>778.0});
+
+        let cards = vec![
+            card("D7"),
+            card("S7"),
+            card("H7"),
+            card("C7"),
+            card("C7"),
+            card("HK"),
+        ];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(5, b.count_cards(7));
+        assert_eq!(Result::Banker(4), b.result());
+        let m = payout_map(&b);
+        assert_eq!(m, hashmap!{Banker=>2.0, Super7=>78.0});
+
+        // banker stands on 4 against a player third card of 0
+        let cards = vec![card("D7"), card("S7"), card("H7"), card("C7"), card("SK")];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(4, b.count_cards(7));
+        assert_eq!(Result::Tie(4), b.result());
+        let m = payout_map(&b);
+        assert_eq!(m, hashmap!{Banker=>1.0, Player=>1.0, Tie=>8.0, Super7=>16.0});
+    }
+
+    #[test]
+    fn test_split_config() {
+        let config = SevenupConfig {
+            banker7: 2.0,
+            player7: 2.0,
+            tie: 9.0,
+            tie7: 9.0,
+            super7: SUPER7_ENHANCED,
+        };
+        let cards = vec![card("D7"), card("C2"), card("CJ"), card("CA"), card("HJ")];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(Result::Player(7), b.result());
+        assert_eq!(payout_map_with(&b, &config), hashmap!{Player=>2.0});
+
+        let cards = vec![card("D7"), card("H9"), card("HQ"), card("H8")];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(Result::Tie(7), b.result());
+        let m = payout_map_with(&b, &config);
+        assert_eq!(m, hashmap!{Banker=>1.0, Player=>1.0, Tie=>9.0});
+
+        let cards = vec![
+            card("D7"),
+            card("D5"),
+            card("D7"),
+            card("H6"),
+            card("CT"),
+            card("S6"),
+        ];
+        let b = Baccarat::from(&cards).unwrap();
+        assert_eq!(Result::Banker(7), b.result());
+        let m = payout_map_with(&b, &config);
+        assert_eq!(m, hashmap!{Banker=>2.0, Super7=>3.0});
+        let config = SevenupConfig {
+            super7: SUPER7_HIGH_ONLY,
+            ..config
+        };
+        assert_eq!(payout_map_with(&b, &config), hashmap!{Banker=>2.0});
+    }
+
+    #[test]
+    fn test_seeded_from_shoe() {
+        let mut shoe = new_shoe(8);
+        let dealer = seed_from_shoe(&mut shoe).unwrap();
+        assert_eq!(8 * 52 - 1, shoe.len());
+        assert_eq!(card("D7"), dealer.player_cards[0]);
+        let cards = vec![card("S7"), card("H7"), card("C7"), card("SK")];
+        let b = Baccarat::from_dealer(dealer, &cards).unwrap();
+        assert_eq!(Result::Tie(4), b.result());
+        assert_eq!(payout_map(&b)[&Super7], 16.0);
     }
 
     #[test]
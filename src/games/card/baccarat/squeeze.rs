@@ -0,0 +1,225 @@
+use super::common::Baccarat;
+use super::dealer::{init_baccarat_dealer, BaccaratDealer, BaccaratStatus};
+use super::total_points;
+use games::card::Card;
+
+// card position on the table, numbered from 1 on each side
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Position {
+    Player(usize),
+    Banker(usize),
+}
+
+// the first four cards are always dealt in this order
+static FIRST4: [Position; 4] = [
+    Position::Player(1),
+    Position::Banker(1),
+    Position::Player(2),
+    Position::Banker(2),
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SqueezeEvent {
+    Dealt(Position),
+    Revealed(Position, Card),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SqueezeError {
+    // the next position depends on cards not revealed yet
+    CardsHidden,
+    // every required card has been dealt
+    HandDone,
+    NotDealt(Position),
+    AlreadyRevealed(Position),
+}
+
+// what the API streams while the cards are squeezed
+#[derive(Clone, Debug, PartialEq)]
+pub struct SqueezeState {
+    pub player_cards: Vec<Option<Card>>,
+    pub banker_cards: Vec<Option<Card>>,
+    // totals of the revealed cards so far
+    pub player_points: u8,
+    pub banker_points: u8,
+    // None until the cards deciding the next position are revealed
+    pub status: Option<BaccaratStatus>,
+    pub next: Option<Position>,
+    pub done: bool,
+}
+
+// Cards are dealt face down and revealed in any order. Revealed cards are fed
+// to a BaccaratDealer in deal order, so the drawing rules decide the third
+// cards once the first four are known.
+pub struct SqueezeDealer {
+    dealer: BaccaratDealer,
+    slots: Vec<(Position, Option<Card>)>,
+    fed: usize,
+    events: Vec<SqueezeEvent>,
+}
+
+impl Default for SqueezeDealer {
+    fn default() -> SqueezeDealer {
+        SqueezeDealer::new()
+    }
+}
+
+impl SqueezeDealer {
+    pub fn new() -> SqueezeDealer {
+        SqueezeDealer {
+            dealer: init_baccarat_dealer(),
+            slots: vec![],
+            fed: 0,
+            events: vec![],
+        }
+    }
+
+    pub fn events(&self) -> &[SqueezeEvent] {
+        &self.events
+    }
+
+    // known status of the dealer, once every dealt card is revealed
+    fn status(&self) -> Option<BaccaratStatus> {
+        if self.fed == self.slots.len() {
+            Some(self.dealer.status)
+        } else {
+            None
+        }
+    }
+
+    pub fn next_position(&self) -> Result<Option<Position>, SqueezeError> {
+        if self.slots.len() < 4 {
+            return Ok(Some(FIRST4[self.slots.len()]));
+        }
+        match self.status() {
+            None => Err(SqueezeError::CardsHidden),
+            Some(BaccaratStatus::Done) => Ok(None),
+            Some(BaccaratStatus::ExpectPlayer) => Ok(Some(Position::Player(self.dealer.player_cards.len() + 1))),
+            Some(BaccaratStatus::ExpectBanker) => Ok(Some(Position::Banker(self.dealer.banker_cards.len() + 1))),
+        }
+    }
+
+    pub fn deal_hidden(&mut self) -> Result<Position, SqueezeError> {
+        let p = self.next_position()?.ok_or(SqueezeError::HandDone)?;
+        self.slots.push((p, None));
+        self.events.push(SqueezeEvent::Dealt(p));
+        Ok(p)
+    }
+
+    pub fn reveal(&mut self, position: Position, card: Card) -> Result<(), SqueezeError> {
+        {
+            let slot = self.slots
+                .iter_mut()
+                .find(|s| s.0 == position)
+                .ok_or(SqueezeError::NotDealt(position))?;
+            if slot.1.is_some() {
+                return Err(SqueezeError::AlreadyRevealed(position));
+            }
+            slot.1 = Some(card);
+        }
+        self.events.push(SqueezeEvent::Revealed(position, card));
+        while let Some(&(_, Some(c))) = self.slots.get(self.fed) {
+            self.dealer.deal(c);
+            self.fed += 1;
+        }
+        Ok(())
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.status() == Some(BaccaratStatus::Done)
+    }
+
+    // the hand can only be settled once every required card is revealed.
+    pub fn result(&self) -> Result<Baccarat, SqueezeError> {
+        if !self.is_done() {
+            return Err(SqueezeError::CardsHidden);
+        }
+        let cards: Vec<Card> = self.slots.iter().filter_map(|s| s.1).collect();
+        Baccarat::from(&cards).ok_or(SqueezeError::CardsHidden)
+    }
+
+    pub fn state(&self) -> SqueezeState {
+        let side = |player: bool| -> Vec<Option<Card>> {
+            self.slots
+                .iter()
+                .filter(|s| match s.0 {
+                    Position::Player(_) => player,
+                    Position::Banker(_) => !player,
+                })
+                .map(|s| s.1)
+                .collect()
+        };
+        let points = |cards: &[Option<Card>]| total_points(&cards.iter().filter_map(|&c| c).collect());
+        let player_cards = side(true);
+        let banker_cards = side(false);
+        SqueezeState {
+            player_points: points(&player_cards),
+            banker_points: points(&banker_cards),
+            player_cards,
+            banker_cards,
+            status: self.status(),
+            next: self.next_position().unwrap_or(None),
+            done: self.is_done(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::baccarat::common::Result as HandResult;
+    use games::card::serde::str_to_card;
+
+    fn card(s: &str) -> Card {
+        str_to_card(s).unwrap()
+    }
+
+    fn deal4(d: &mut SqueezeDealer) {
+        for p in FIRST4.iter() {
+            assert_eq!(d.deal_hidden(), Ok(*p));
+        }
+    }
+
+    #[test]
+    fn test_natural() {
+        let mut d = SqueezeDealer::new();
+        deal4(&mut d);
+        assert_eq!(d.deal_hidden(), Err(SqueezeError::CardsHidden));
+        assert_eq!(d.result().err(), Some(SqueezeError::CardsHidden));
+        d.reveal(Position::Banker(1), card("S9")).unwrap();
+        d.reveal(Position::Player(1), card("ST")).unwrap();
+        let s = d.state();
+        assert_eq!(s.player_cards, vec![Some(card("ST")), None]);
+        assert_eq!(s.banker_points, 9);
+        assert_eq!(s.status, None);
+        assert_eq!(s.next, None);
+        d.reveal(Position::Banker(2), card("DQ")).unwrap();
+        assert!(!d.is_done());
+        d.reveal(Position::Player(2), card("H2")).unwrap();
+        assert!(d.is_done());
+        assert_eq!(d.deal_hidden(), Err(SqueezeError::HandDone));
+        assert_eq!(d.result().unwrap().result(), HandResult::Banker(9));
+        assert_eq!(d.events().len(), 8);
+        assert_eq!(d.events()[4], SqueezeEvent::Revealed(Position::Banker(1), card("S9")));
+    }
+
+    #[test]
+    fn test_third_cards() {
+        let mut d = SqueezeDealer::new();
+        deal4(&mut d);
+        d.reveal(Position::Player(1), card("C6")).unwrap();
+        d.reveal(Position::Banker(1), card("DQ")).unwrap();
+        d.reveal(Position::Player(2), card("DJ")).unwrap();
+        assert_eq!(d.reveal(Position::Player(2), card("DJ")), Err(SqueezeError::AlreadyRevealed(Position::Player(2))));
+        assert_eq!(d.reveal(Position::Banker(3), card("DJ")), Err(SqueezeError::NotDealt(Position::Banker(3))));
+        d.reveal(Position::Banker(2), card("SJ")).unwrap();
+        let s = d.state();
+        assert_eq!(s.status, Some(BaccaratStatus::ExpectBanker));
+        assert_eq!(s.next, Some(Position::Banker(3)));
+        assert_eq!((s.player_points, s.banker_points), (6, 0));
+        assert_eq!(d.deal_hidden(), Ok(Position::Banker(3)));
+        assert_eq!(d.result().err(), Some(SqueezeError::CardsHidden));
+        d.reveal(Position::Banker(3), card("D3")).unwrap();
+        assert_eq!(d.result().unwrap().result(), HandResult::Player(6));
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet};
+use super::common::{Baccarat, Result};
+use super::lucky::{self, LuckyBet, Paytable};
+use games::{BetSerde, Game};
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Bets {
+    Banker,
+    Player,
+    Tie,
+    Tiger,
+    SmallTiger,
+    BigTiger,
+    TigerTie,
+    TigerPair,
+}
+
+use self::Bets::*;
+
+impl BetSerde for Bets {
+    fn to_u16(&self) -> u16 {
+        match *self {
+            Banker => 1,
+            Player => 2,
+            Tie => 3,
+            Tiger => 4,
+            SmallTiger => 5,
+            BigTiger => 6,
+            TigerTie => 7,
+            TigerPair => 8,
+        }
+    }
+
+    fn from_u16(id: u16) -> Option<Bets> {
+        match id {
+            1 => Some(Banker),
+            2 => Some(Player),
+            3 => Some(Tie),
+            4 => Some(Tiger),
+            5 => Some(SmallTiger),
+            6 => Some(BigTiger),
+            7 => Some(TigerTie),
+            8 => Some(TigerPair),
+            _ => None,
+        }
+    }
+}
+
+lazy_static! {
+    static ref ALL_BETS:HashSet<Bets> = hashset!{ Banker, Player, Tie, Tiger, SmallTiger, BigTiger, TigerTie, TigerPair };
+    static ref BETS_AFTER70:HashSet<Bets> = hashset!{ Banker, Player, Tie, Tiger, SmallTiger, BigTiger, TigerTie };
+}
+
+pub const MAIN_BETS: [Bets; 3] = [Banker, Player, Tie];
+
+pub fn valid_bets(hands: usize) -> &'static HashSet<Bets> {
+    if hands <= 70 {
+        &ALL_BETS
+    } else {
+        &BETS_AFTER70
+    }
+}
+
+pub struct TigerBaccaratGame;
+
+impl Game for TigerBaccaratGame {
+    type B = Bets;
+
+    fn name(&self) -> &'static str {
+        "tiger_baccarat"
+    }
+
+    fn main_bets(&self) -> &[Bets] {
+        &MAIN_BETS
+    }
+}
+
+// banker wins with 6, by the number of banker cards
+const TIGER: Paytable = Paytable::ByCards(&[(2, 13.0), (3, 21.0)]);
+const SMALL_TIGER: Paytable = Paytable::ByCards(&[(2, 23.0)]);
+const BIG_TIGER: Paytable = Paytable::ByCards(&[(3, 51.0)]);
+
+pub fn payout_map(b: &Baccarat) -> HashMap<Bets, f64> {
+    let result = b.result();
+    let mut map = result_payout_map(result);
+    side_bet(b, &mut map);
+    map
+}
+
+#[inline]
+fn side_bet(b: &Baccarat, map: &mut HashMap<Bets, f64>) {
+    for &(bet, t) in [(Tiger, TIGER), (SmallTiger, SMALL_TIGER), (BigTiger, BIG_TIGER)].iter() {
+        if let Some(r) = lucky::payout(b, LuckyBet::BankerWins6, &t) {
+            map.insert(bet, r);
+        }
+    }
+    if let Some(r) = tiger_pair(b) {
+        map.insert(TigerPair, r);
+    }
+}
+
+// a pair on one side 4:1, on both sides 20:1, the same pair on both sides 100:1
+fn tiger_pair(b: &Baccarat) -> Option<f64> {
+    let (b1, b2) = b.banker_first2();
+    let (p1, p2) = b.player_first2();
+    match (b1.is_same_rank(&b2), p1.is_same_rank(&p2)) {
+        (true, true) if b1.is_same_rank(&p1) => Some(101.0),
+        (true, true) => Some(21.0),
+        (true, false) | (false, true) => Some(5.0),
+        _ => None,
+    }
+}
+
+#[inline]
+fn result_payout_map(result: Result) -> HashMap<Bets, f64> {
+    let mut map = HashMap::<Bets, f64>::new();
+    match result {
+        Result::Tie(t) => {
+            if t == 6 {
+                map.insert(TigerTie, 36.0);
+            }
+            map.insert(Banker, 1.0);
+            map.insert(Player, 1.0);
+            map.insert(Tie, 9.0);
+        }
+        Result::Player(_) => {
+            map.insert(Player, 2.0);
+        }
+        Result::Banker(6) => {
+            map.insert(Banker, 1.5);
+        }
+        Result::Banker(_) => {
+            map.insert(Banker, 2.0);
+        }
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::serde::str_to_card;
+    use games::card::Card;
+
+    fn hand(cards: &[&str]) -> Baccarat {
+        let cards: Vec<Card> = cards.iter().map(|s| str_to_card(s).unwrap()).collect();
+        Baccarat::from(&cards).unwrap()
+    }
+
+    #[test]
+    fn test_valid_bets() {
+        assert_eq!(8, valid_bets(1).len());
+        assert_eq!(7, valid_bets(71).len());
+    }
+
+    #[test]
+    fn test_small_tiger() {
+        // player T 2 T = 2, banker 6 T = 6
+        let b = hand(&["ST", "S6", "S2", "HT", "DT"]);
+        assert_eq!(payout_map(&b), hashmap!{Banker => 1.5, Tiger => 13.0, SmallTiger => 23.0});
+    }
+
+    #[test]
+    fn test_big_tiger() {
+        // player T 2 3 = 5, banker 3 T 3 = 6
+        let b = hand(&["ST", "S3", "S2", "HT", "S3", "D3"]);
+        assert_eq!(payout_map(&b), hashmap!{Banker => 1.5, Tiger => 21.0, BigTiger => 51.0});
+    }
+
+    #[test]
+    fn test_tiger_tie() {
+        // player 6 T = 6, banker 6 K = 6
+        let b = hand(&["S6", "H6", "ST", "DK"]);
+        assert_eq!(payout_map(&b), hashmap!{Banker => 1.0, Player => 1.0, Tie => 9.0, TigerTie => 36.0});
+    }
+
+    #[test]
+    fn test_tiger_pair() {
+        // player 9 9 = 8, banker K T = 0
+        let b = hand(&["S9", "DK", "H9", "DT"]);
+        assert_eq!(payout_map(&b), hashmap!{Player => 2.0, TigerPair => 5.0});
+        // player 9 9 = 8, banker 4 4 = 8
+        let b = hand(&["S9", "D4", "H9", "C4"]);
+        assert_eq!(payout_map(&b), hashmap!{Banker => 1.0, Player => 1.0, Tie => 9.0, TigerPair => 21.0});
+        // player 9 9 = 8, banker 9 9 = 8
+        let b = hand(&["S9", "D9", "H9", "C9"]);
+        assert_eq!(payout_map(&b)[&TigerPair], 101.0);
+    }
+}
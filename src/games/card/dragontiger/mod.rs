@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use super::{Card, Rank};
+use games::{BetSerde, Game};
 
 #[derive(Hash, PartialEq, Eq, Debug)]
 pub enum Bets {
@@ -13,8 +14,44 @@ pub enum Bets {
 }
 
 use self::Bets::*;
+
+impl BetSerde for Bets {
+    fn to_u16(&self) -> u16 {
+        match *self {
+            Dragon => 1,
+            Tiger => 2,
+            Tie => 3,
+            DragonOdd => 4,
+            DragonEven => 5,
+            TigerOdd => 6,
+            TigerEven => 7,
+        }
+    }
+
+    fn from_u16(id: u16) -> Option<Bets> {
+        match id {
+            1 => Some(Dragon),
+            2 => Some(Tiger),
+            3 => Some(Tie),
+            4 => Some(DragonOdd),
+            5 => Some(DragonEven),
+            6 => Some(TigerOdd),
+            7 => Some(TigerEven),
+            _ => None,
+        }
+    }
+}
+
 pub struct DragonTigerGame;
 
+impl Game for DragonTigerGame {
+    type B = Bets;
+
+    fn name(&self) -> &'static str {
+        "dragontiger"
+    }
+}
+
 lazy_static! {
     static ref ALL_BETS:HashSet<Bets> = hashset!{Dragon,Tiger,Tie, DragonEven, DragonOdd, TigerOdd, TigerEven};
     static ref BETS_AFTER60:HashSet<Bets> = hashset!{Dragon,Tiger,Tie};
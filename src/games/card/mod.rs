@@ -28,6 +28,24 @@ pub enum Rank {
     King,
 }
 
+pub static ALL_SUITS: &[Suit] = &[Suit::Diamond, Suit::Club, Suit::Heart, Suit::Spade];
+
+pub static ALL_RANKS: &[Rank] = &[
+    Rank::Ace,
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+];
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Card {
     pub suit: Suit,
@@ -55,3 +73,16 @@ impl Card {
         !self.is_red()
     }
 }
+
+// unshuffled cards of the given number of decks
+pub fn new_shoe(decks: usize) -> Vec<Card> {
+    let mut cards = Vec::with_capacity(decks * 52);
+    for _ in 0..decks {
+        for &suit in ALL_SUITS {
+            for &rank in ALL_RANKS {
+                cards.push(Card { suit, rank });
+            }
+        }
+    }
+    cards
+}
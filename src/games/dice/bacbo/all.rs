@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use super::{simple, tie, BetKind, Result};
+use games::rng::{self, RandomSource};
+use games::Game;
+
+pub struct BacBo {
+    all_bets: HashMap<u16, Box<BetKind>>,
+}
+
+impl Default for BacBo {
+    fn default() -> BacBo {
+        BacBo::new()
+    }
+}
+
+impl BacBo {
+    pub fn new() -> BacBo {
+        let mut map = HashMap::<u16, Box<BetKind>>::new();
+        simple::all_bets(&mut map);
+        tie::all_bets(&mut map);
+        BacBo { all_bets: map }
+    }
+
+    // player dice first, then the banker dice
+    pub fn roll<R: RandomSource>(&self, src: &mut R) -> (u8, u8, u8, u8) {
+        (rng::roll(src), rng::roll(src), rng::roll(src), rng::roll(src))
+    }
+
+    pub fn valid_bets(&self, bet_id: u16) -> bool {
+        self.all_bets.contains_key(&bet_id)
+    }
+
+    // p1, p2 are the player dice and b1, b2 the banker dice, nothing is paid
+    // when a die is out of 1 to 6
+    pub fn payout_map(&self, p1: u8, p2: u8, b1: u8, b2: u8) -> HashMap<u16, f64> {
+        let mut map = HashMap::<u16, f64>::new();
+        if [p1, p2, b1, b2].iter().any(|d| !(1..=6).contains(d)) {
+            return map;
+        }
+        let r = Result::new(p1, p2, b1, b2);
+        for (&id, b) in &self.all_bets {
+            if b.bingo(&r) {
+                map.insert(id, b.odds(&r) + 1.0);
+            } else if b.refund(&r) > 0.0 {
+                map.insert(id, b.refund(&r));
+            }
+        }
+        map
+    }
+}
+
+impl Game for BacBo {
+    type B = u16;
+
+    fn name(&self) -> &'static str {
+        "bacbo"
+    }
+
+    fn accepts_bet(&self, &id: &u16) -> bool {
+        self.valid_bets(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payout_map() {
+        let g = BacBo::new();
+        assert_eq!(g.payout_map(6, 5, 3, 3), hashmap!{1=>2.0});
+        assert_eq!(g.payout_map(1, 2, 6, 6), hashmap!{2=>2.0});
+        assert_eq!(g.payout_map(6, 6, 6, 6), hashmap!{1=>0.9, 2=>0.9, 3=>89.0});
+        assert_eq!(g.payout_map(3, 4, 2, 5), hashmap!{1=>0.9, 2=>0.9, 3=>5.0});
+        assert!(!g.valid_bets(4));
+        assert_eq!(g.from_raw_bets(&hashmap!{4=>1.0}), None);
+    }
+
+    #[test]
+    fn test_rtp() {
+        let g = BacBo::new();
+        let mut rtp = HashMap::<u16, f64>::new();
+        for p1 in 1..7 {
+            for p2 in 1..7 {
+                for b1 in 1..7 {
+                    for b2 in 1..7 {
+                        for (id, v) in g.payout_map(p1, p2, b1, b2) {
+                            *rtp.entry(id).or_insert(0.0) += v;
+                        }
+                    }
+                }
+            }
+        }
+        // 575 wins at 2 and 146 ties at 0.9 of 1296
+        assert!((rtp[&1] - 1281.4).abs() < 1e-9);
+        assert!((rtp[&2] - 1281.4).abs() < 1e-9);
+        assert!((rtp[&3] - 1238.0).abs() < 1e-9);
+        assert!((rtp[&1] / 1296.0 - 0.98873).abs() < 1e-5);
+        assert!((rtp[&3] / 1296.0 - 0.95525).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_dice_out_of_range() {
+        let g = BacBo::new();
+        assert!(g.payout_map(0, 2, 3, 4).is_empty());
+        assert!(g.payout_map(7, 7, 7, 7).is_empty());
+        assert!(g.payout_map(1, 1, 6, 255).is_empty());
+        assert!(g.payout_map(0, 0, 0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_roll() {
+        let mut src = ::rand::thread_rng();
+        let g = BacBo::new();
+        for _ in 0..1000 {
+            let (p1, p2, b1, b2) = g.roll(&mut src);
+            assert!(!g.payout_map(p1, p2, b1, b2).is_empty());
+        }
+    }
+}
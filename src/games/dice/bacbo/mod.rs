@@ -0,0 +1,43 @@
+pub mod simple;
+pub mod tie;
+pub mod all;
+
+use std::collections::HashMap;
+use super::{BetId, Ratio};
+
+// two dice for each side, the higher total wins
+pub struct Result {
+    player: u8,
+    banker: u8,
+}
+
+impl Result {
+    fn new(p1: u8, p2: u8, b1: u8, b2: u8) -> Result {
+        Result {
+            player: p1 + p2,
+            banker: b1 + b2,
+        }
+    }
+
+    fn is_tie(&self) -> bool {
+        self.player == self.banker
+    }
+}
+
+pub trait BetKind: BetId + Ratio {
+    fn bingo(&self, r: &Result) -> bool;
+
+    // winnings per unit staked
+    fn odds(&self, _r: &Result) -> f64 {
+        self.ratio()
+    }
+
+    // part of the stake returned when the bet does not win
+    fn refund(&self, _r: &Result) -> f64 {
+        0.0
+    }
+}
+
+fn add(b: Box<BetKind>, map: &mut HashMap<u16, Box<BetKind>>) {
+    map.insert(b.id(), b);
+}
@@ -0,0 +1,64 @@
+use super::{add, BetKind, Result};
+use games::dice::{BetId, Ratio};
+use std::collections::HashMap;
+
+enum Simple {
+    Player,
+    Banker,
+}
+
+impl BetId for Simple {
+    fn id(&self) -> u16 {
+        match *self {
+            Simple::Player => 1,
+            Simple::Banker => 2,
+        }
+    }
+}
+
+impl Ratio for Simple {
+    fn ratio(&self) -> f64 {
+        1.0
+    }
+}
+
+impl BetKind for Simple {
+    fn bingo(&self, r: &Result) -> bool {
+        match *self {
+            Simple::Player => r.player > r.banker,
+            Simple::Banker => r.banker > r.player,
+        }
+    }
+
+    // 90% of the stake back on a tie
+    fn refund(&self, r: &Result) -> f64 {
+        if r.is_tie() {
+            0.9
+        } else {
+            0.0
+        }
+    }
+}
+
+pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    add(Box::new(Simple::Player), map);
+    add(Box::new(Simple::Banker), map);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bingo() {
+        let r = Result::new(6, 5, 3, 3);
+        assert!(Simple::Player.bingo(&r));
+        assert!(!Simple::Banker.bingo(&r));
+        assert_eq!(Simple::Banker.refund(&r), 0.0);
+
+        let r = Result::new(1, 6, 3, 4);
+        assert!(!Simple::Player.bingo(&r));
+        assert!(!Simple::Banker.bingo(&r));
+        assert_eq!(Simple::Player.refund(&r), 0.9);
+    }
+}
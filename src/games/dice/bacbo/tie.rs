@@ -0,0 +1,57 @@
+use super::{add, BetKind, Result};
+use games::dice::{BetId, Ratio};
+use std::collections::HashMap;
+
+struct Tie;
+
+impl BetId for Tie {
+    fn id(&self) -> u16 {
+        3
+    }
+}
+
+impl Ratio for Tie {
+    // the lowest tie multiplier
+    fn ratio(&self) -> f64 {
+        4.0
+    }
+}
+
+#[inline]
+fn ratio_impl(total: u8) -> f64 {
+    match total {
+        2 | 12 => 88.0,
+        3 | 11 => 25.0,
+        4 | 10 => 10.0,
+        5 | 9 => 6.0,
+        6..=8 => 4.0,
+        _ => unreachable!(),
+    }
+}
+
+impl BetKind for Tie {
+    fn bingo(&self, r: &Result) -> bool {
+        r.is_tie()
+    }
+
+    fn odds(&self, r: &Result) -> f64 {
+        ratio_impl(r.player)
+    }
+}
+
+pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    add(Box::new(Tie), map);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_odds() {
+        assert!(!Tie.bingo(&Result::new(1, 1, 1, 2)));
+        assert_eq!(Tie.odds(&Result::new(1, 1, 1, 1)), 88.0);
+        assert_eq!(Tie.odds(&Result::new(5, 6, 6, 5)), 25.0);
+        assert_eq!(Tie.odds(&Result::new(3, 4, 2, 5)), 4.0);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod sicbo;
 pub mod roulette;
+pub mod bacbo;
 
 pub trait Ratio {
     fn ratio(&self)->f64;
@@ -1,12 +1,38 @@
 use std::collections::HashMap;
-use super::{corner, line, split, straight, street, simple, BetKind};
+use super::{corner, double, five, line, split, straight, street, simple, BetKind, EvenMoney, Wheel,
+            DOUBLE_ZERO};
+use super::catalogue::{self, CatalogueEntry};
+use super::double::DoubleBetKind;
+use super::compound::{finals, CompoundBet};
+use super::lightning::{LightningDraw, LIGHTNING_STRAIGHT_RATIO};
+use super::racetrack::{CallBet, AMERICAN_WHEEL, EUROPEAN_WHEEL};
+use games::rng::{self, RandomSource};
+use games::Game;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallBetResult {
+    pub call: CallBet,
+    pub stake: f64,
+    pub payout: f64,
+}
 
 pub struct Roulette {
+    wheel: Wheel,
+    even_money: EvenMoney,
     all_bets: HashMap<u16, Box<BetKind>>,
+    double_bets: HashMap<u16, Box<DoubleBetKind>>,
 }
 
 impl Roulette {
     pub fn new() -> Roulette {
+        Roulette::with_wheel(Wheel::European)
+    }
+
+    pub fn with_wheel(wheel: Wheel) -> Roulette {
+        Roulette::with_rules(wheel, EvenMoney::Standard)
+    }
+
+    pub fn with_rules(wheel: Wheel, even_money: EvenMoney) -> Roulette {
         let mut map = HashMap::<u16, Box<BetKind>>::new();
         simple::all_bets(&mut map);
         straight::all_bets(&mut map);
@@ -14,7 +40,44 @@ impl Roulette {
         street::all_bets(&mut map);
         corner::all_bets(&mut map);
         line::all_bets(&mut map);
-        Roulette { all_bets: map }
+        if wheel == Wheel::American {
+            // 0/3 split, 0-2-3 trio and the first four are not on the double zero layout
+            for id in &[202, 301, 422] {
+                map.remove(id);
+            }
+            straight::american_bets(&mut map);
+            split::american_bets(&mut map);
+            street::american_bets(&mut map);
+            five::american_bets(&mut map);
+        }
+        let mut double_bets = HashMap::<u16, Box<DoubleBetKind>>::new();
+        double::all_bets(&mut double_bets);
+        Roulette {
+            wheel,
+            even_money,
+            all_bets: map,
+            double_bets,
+        }
+    }
+
+    pub fn wheel(&self) -> Wheel {
+        self.wheel
+    }
+
+    pub fn even_money(&self) -> EvenMoney {
+        self.even_money
+    }
+
+    pub fn pockets(&self) -> u8 {
+        match self.wheel {
+            Wheel::European => 37,
+            Wheel::American => 38,
+        }
+    }
+
+    // the ball of the next spin on this wheel
+    pub fn spin<R: RandomSource>(&self, src: &mut R) -> u8 {
+        rng::spin(src, self.pockets())
     }
 
     pub fn valid_bets(&self, bet_id: u16) -> bool {
@@ -22,18 +85,222 @@ impl Roulette {
     }
 
     pub fn payout_map(&self, ball: u8) -> HashMap<u16, f64> {
-        self.all_bets
+        let mut map: HashMap<u16, f64> = self.all_bets
             .iter()
             .filter(|&(_, b)| b.bingo(ball))
             .map(|(&id, b)| (id, b.ratio()+1.0))
+            .collect();
+        if is_zero(ball) && self.even_money == EvenMoney::LaPartage {
+            for id in self.even_money_bets() {
+                map.insert(id, 0.5);
+            }
+        }
+        map
+    }
+
+    // every bet is settled once per ball it wins on, the stake is returned once.
+    pub fn multi_ball_payout_map(&self, balls: &[u8]) -> HashMap<u16, f64> {
+        let mut map = HashMap::<u16, f64>::new();
+        for (&id, b) in &self.all_bets {
+            let c = b.hits(balls);
+            if c > 0 {
+                map.insert(id, b.ratio() * f64::from(c) + 1.0);
+            }
+        }
+        map
+    }
+
+    pub fn valid_double_ball_bets(&self, bet_id: u16) -> bool {
+        self.all_bets.contains_key(&bet_id) || self.double_bets.contains_key(&bet_id)
+    }
+
+    // the table bets settled on both balls plus the double ball bets.
+    pub fn double_ball_payout_map(&self, a: u8, b: u8) -> HashMap<u16, f64> {
+        let mut map = self.multi_ball_payout_map(&[a, b]);
+        for (&id, d) in &self.double_bets {
+            if d.bingo(a, b) {
+                map.insert(id, d.ratio() + 1.0);
+            }
+        }
+        map
+    }
+
+    // straight ups pay 29:1, or the multiplier when the number is struck.
+    pub fn lightning_payout_map(&self, ball: u8, draw: &LightningDraw) -> HashMap<u16, f64> {
+        let mut map = self.payout_map(ball);
+        let straight = 100 + u16::from(ball);
+        if map.contains_key(&straight) {
+            let ratio = match draw.0.get(&ball) {
+                Some(&m) => f64::from(m),
+                None => LIGHTNING_STRAIGHT_RATIO,
+            };
+            map.insert(straight, ratio + 1.0);
+        }
+        map
+    }
+
+    // even money bets to be kept for the next spin under En Prison.
+    pub fn imprisoned(&self, ball: u8) -> Vec<u16> {
+        if is_zero(ball) && self.even_money == EvenMoney::EnPrison {
+            self.even_money_bets()
+        } else {
+            vec![]
+        }
+    }
+
+    // imprisoned bets get the stake back if they win the next spin, otherwise
+    // they are lost, including another zero.
+    pub fn prison_payout_map(&self, ball: u8) -> HashMap<u16, f64> {
+        self.all_bets
+            .iter()
+            .filter(|&(_, b)| b.ratio() == 1.0 && b.bingo(ball))
+            .map(|(&id, _)| (id, 1.0))
+            .collect()
+    }
+
+    pub fn wheel_order(&self) -> &'static [u8] {
+        match self.wheel {
+            Wheel::European => EUROPEAN_WHEEL,
+            Wheel::American => AMERICAN_WHEEL,
+        }
+    }
+
+    // expands an announced bet into the stake on each bet id, None if it is
+    // not available on this wheel.
+    pub fn expand_call_bet(&self, call: CallBet, unit: f64) -> Option<HashMap<u16, f64>> {
+        let chips = call.chips(self.wheel_order())?;
+        let mut map = HashMap::<u16, f64>::new();
+        for (id, n) in chips {
+            if !self.valid_bets(id) {
+                return None;
+            }
+            *map.entry(id).or_insert(0.0) += unit * f64::from(n);
+        }
+        Some(map)
+    }
+
+    pub fn settle_call_bet(&self, call: CallBet, unit: f64, ball: u8) -> Option<CallBetResult> {
+        let bets = self.expand_call_bet(call, unit)?;
+        let payouts = self.payout_map(ball);
+        let (stake, payout) = bets.iter().fold((0.0, 0.0), |(s, p), (id, v)| {
+            (s + v, p + v * payouts.get(id).cloned().unwrap_or(0.0))
+        });
+        Some(CallBetResult {
+            call,
+            stake,
+            payout,
+        })
+    }
+
+    // numbers of the wheel covered by the bet
+    pub fn covered(&self, bet_id: u16) -> Vec<u8> {
+        match self.all_bets.get(&bet_id) {
+            Some(b) => (0..self.pockets()).filter(|&n| b.bingo(n)).collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn catalogue_entry(&self, bet_id: u16) -> Option<CatalogueEntry> {
+        let b = self.all_bets.get(&bet_id)?;
+        catalogue::entry(bet_id, self.covered(bet_id), b.ratio(), self.wheel)
+    }
+
+    // every bet of the layout, ordered by id.
+    pub fn catalogue(&self) -> Vec<CatalogueEntry> {
+        let mut ids: Vec<u16> = self.all_bets.keys().cloned().collect();
+        ids.sort();
+        ids.into_iter().filter_map(|id| self.catalogue_entry(id)).collect()
+    }
+
+    // the bet id covering exactly these numbers, inside bets only.
+    fn inside_bet(&self, numbers: &[u8]) -> Option<u16> {
+        self.all_bets
+            .keys()
+            .cloned()
+            .find(|&id| self.covered(id) == numbers)
+    }
+
+    pub fn expand_compound_bet(&self, bet: CompoundBet, unit: f64) -> Option<HashMap<u16, f64>> {
+        let mut map = HashMap::<u16, f64>::new();
+        match bet {
+            CompoundBet::FinalePlein(d) => {
+                if d > 9 {
+                    return None;
+                }
+                for n in finals(d) {
+                    map.insert(self.inside_bet(&[n])?, unit);
+                }
+            }
+            CompoundBet::FinaleCheval(d1, d2) => {
+                if d1 >= d2 || d2 > 9 {
+                    return None;
+                }
+                for t in 0..4 {
+                    let (a, b) = (t * 10 + d1, t * 10 + d2);
+                    let numbers = if b <= 36 { vec![a, b] } else if a <= 36 { vec![a] } else { vec![] };
+                    if !numbers.is_empty() {
+                        map.insert(self.inside_bet(&numbers)?, unit);
+                    }
+                }
+            }
+            CompoundBet::Complete(n) => {
+                if n >= self.pockets() {
+                    return None;
+                }
+                for &id in self.all_bets.keys() {
+                    let c = self.covered(id);
+                    if c.len() <= 4 && c.contains(&n) {
+                        map.insert(id, unit * c.len() as f64);
+                    }
+                }
+            }
+        }
+        Some(map)
+    }
+
+    // the most the player can win net of the total stake over every ball.
+    pub fn max_exposure(&self, bets: &HashMap<u16, f64>) -> f64 {
+        let stake = bets.values().fold(0.0, |a, v| a + v);
+        (0..self.pockets())
+            .map(|ball| {
+                let payouts = self.payout_map(ball);
+                bets.iter().fold(0.0, |a, (id, v)| {
+                    a + v * payouts.get(id).cloned().unwrap_or(0.0)
+                })
+            })
+            .fold(0.0, f64::max) - stake
+    }
+
+    fn even_money_bets(&self) -> Vec<u16> {
+        self.all_bets
+            .iter()
+            .filter(|&(_, b)| b.ratio() == 1.0)
+            .map(|(&id, _)| id)
             .collect()
     }
 }
 
+fn is_zero(ball: u8) -> bool {
+    ball == 0 || ball == DOUBLE_ZERO
+}
+
+impl Game for Roulette {
+    type B = u16;
+
+    fn name(&self) -> &'static str {
+        "roulette"
+    }
+
+    fn accepts_bet(&self, &id: &u16) -> bool {
+        self.valid_bets(id)
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use games::dice::roulette::DOUBLE_ZERO;
 
     #[test]
     fn test_payout_map_0(){
@@ -42,6 +309,241 @@ mod tests {
         assert_eq!(r, hashmap!{100=>36.0, 200=>18.0, 201=>18.0, 202=>18.0, 300=>12.0, 301=>12.0, 422=>9.0,});
     }
 
+    #[test]
+    fn test_american_payout_map_0(){
+        let g = Roulette::with_wheel(Wheel::American);
+        let r = g.payout_map(0);
+        assert_eq!(r, hashmap!{100=>36.0, 200=>18.0, 201=>18.0, 262=>18.0, 300=>12.0, 315=>12.0, 500=>7.0});
+    }
+
+    #[test]
+    fn test_american_payout_map_00(){
+        let g = Roulette::with_wheel(Wheel::American);
+        let r = g.payout_map(DOUBLE_ZERO);
+        assert_eq!(r, hashmap!{137=>36.0, 260=>18.0, 261=>18.0, 262=>18.0, 314=>12.0, 315=>12.0, 500=>7.0});
+        assert_eq!(Roulette::new().payout_map(DOUBLE_ZERO), hashmap!{});
+    }
+
+    #[test]
+    fn test_american_payout_map_2(){
+        let g = Roulette::with_wheel(Wheel::American);
+        let r = g.payout_map(2);
+        assert_eq!(r, hashmap!{2=>2.0, 4=>2.0, 6=>2.0, 7=>3.0, 11=>3.0, 102=>36.0, 201=>18.0, 204=>18.0, 236=>18.0, 237=>18.0, 260=>18.0, 300=>12.0, 302=>12.0, 314=>12.0, 315=>12.0, 400=>9.0, 401=>9.0, 500=>7.0, 600=>6.0});
+    }
+
+    #[test]
+    fn test_american_valid_bets(){
+        let g = Roulette::with_wheel(Wheel::American);
+        assert_eq!(g.pockets(), 38);
+        assert!(g.valid_bets(137));
+        assert!(g.valid_bets(500));
+        assert!(!g.valid_bets(202));
+        assert!(!g.valid_bets(301));
+        assert!(!g.valid_bets(422));
+        assert!(!Roulette::new().valid_bets(500));
+        assert_eq!(g.from_raw_bets(&hashmap!{500=>1.0}), Some(hashmap!{500=>1.0}));
+        assert_eq!(Roulette::new().from_raw_bets(&hashmap!{1=>1.0, 500=>1.0}), None);
+    }
+
+    #[test]
+    fn test_la_partage(){
+        let g = Roulette::with_rules(Wheel::European, EvenMoney::LaPartage);
+        let r = g.payout_map(0);
+        assert_eq!(r, hashmap!{1=>0.5, 2=>0.5, 3=>0.5, 4=>0.5, 5=>0.5, 6=>0.5, 100=>36.0, 200=>18.0, 201=>18.0, 202=>18.0, 300=>12.0, 301=>12.0, 422=>9.0,});
+        assert_eq!(g.payout_map(1), Roulette::new().payout_map(1));
+        assert!(g.imprisoned(0).is_empty());
+    }
+
+    #[test]
+    fn test_en_prison(){
+        let g = Roulette::with_rules(Wheel::European, EvenMoney::EnPrison);
+        assert_eq!(g.payout_map(0), Roulette::new().payout_map(0));
+        let mut ids = g.imprisoned(0);
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5, 6]);
+        assert!(g.imprisoned(1).is_empty());
+        assert_eq!(g.prison_payout_map(1), hashmap!{1=>1.0, 3=>1.0, 6=>1.0});
+        assert_eq!(g.prison_payout_map(0), hashmap!{});
+    }
+
+    #[test]
+    fn test_expand_call_bet(){
+        let g = Roulette::new();
+        let m = g.expand_call_bet(CallBet::Voisins, 2.0).unwrap();
+        assert_eq!(m, hashmap!{301=>4.0, 206=>2.0, 214=>2.0, 220=>2.0, 221=>2.0, 234=>2.0, 416=>4.0});
+        let m = g.expand_call_bet(CallBet::Neighbours(0, 1), 1.0).unwrap();
+        assert_eq!(m, hashmap!{126=>1.0, 100=>1.0, 132=>1.0});
+        assert_eq!(g.expand_call_bet(CallBet::Neighbours(40, 1), 1.0), None);
+        let g = Roulette::with_wheel(Wheel::American);
+        assert_eq!(g.expand_call_bet(CallBet::Voisins, 1.0), None);
+        let m = g.expand_call_bet(CallBet::Neighbours(DOUBLE_ZERO, 1), 1.0).unwrap();
+        assert_eq!(m, hashmap!{101=>1.0, 137=>1.0, 127=>1.0});
+    }
+
+    #[test]
+    fn test_settle_call_bet(){
+        let g = Roulette::new();
+        let r = g.settle_call_bet(CallBet::Voisins, 1.0, 0).unwrap();
+        assert_eq!((r.stake, r.payout), (9.0, 24.0));
+        let r = g.settle_call_bet(CallBet::Voisins, 1.0, 26).unwrap();
+        assert_eq!((r.stake, r.payout), (9.0, 18.0));
+        let r = g.settle_call_bet(CallBet::JeuZero, 1.0, 26).unwrap();
+        assert_eq!((r.stake, r.payout), (4.0, 36.0));
+        let r = g.settle_call_bet(CallBet::Tiers, 1.0, 1).unwrap();
+        assert_eq!((r.stake, r.payout), (6.0, 0.0));
+        let r = g.settle_call_bet(CallBet::Orphelins, 1.0, 17).unwrap();
+        assert_eq!((r.stake, r.payout), (5.0, 36.0));
+    }
+
+    #[test]
+    fn test_finale_plein(){
+        let g = Roulette::new();
+        let m = g.expand_compound_bet(CompoundBet::FinalePlein(3), 1.0).unwrap();
+        assert_eq!(m, hashmap!{103=>1.0, 113=>1.0, 123=>1.0, 133=>1.0});
+        let m = g.expand_compound_bet(CompoundBet::FinalePlein(8), 1.0).unwrap();
+        assert_eq!(m, hashmap!{108=>1.0, 118=>1.0, 128=>1.0});
+        assert_eq!(g.max_exposure(&m), 33.0);
+        assert_eq!(g.expand_compound_bet(CompoundBet::FinalePlein(10), 1.0), None);
+    }
+
+    #[test]
+    fn test_finale_cheval(){
+        let g = Roulette::new();
+        let m = g.expand_compound_bet(CompoundBet::FinaleCheval(0, 3), 1.0).unwrap();
+        assert_eq!(m, hashmap!{202=>1.0, 212=>1.0, 222=>1.0, 232=>1.0});
+        let m = g.expand_compound_bet(CompoundBet::FinaleCheval(6, 9), 2.0).unwrap();
+        assert_eq!(m, hashmap!{208=>2.0, 218=>2.0, 228=>2.0, 136=>2.0});
+        assert_eq!(g.max_exposure(&m), 64.0);
+        assert_eq!(g.expand_compound_bet(CompoundBet::FinaleCheval(1, 5), 1.0), None);
+        assert_eq!(g.expand_compound_bet(CompoundBet::FinaleCheval(3, 0), 1.0), None);
+        let g = Roulette::with_wheel(Wheel::American);
+        assert_eq!(g.expand_compound_bet(CompoundBet::FinaleCheval(0, 3), 1.0), None);
+    }
+
+    #[test]
+    fn test_complete(){
+        let g = Roulette::new();
+        let m = g.expand_compound_bet(CompoundBet::Complete(17), 1.0).unwrap();
+        assert_eq!(m, hashmap!{117=>1.0, 216=>2.0, 219=>2.0, 246=>2.0, 247=>2.0, 307=>3.0, 408=>4.0, 409=>4.0, 410=>4.0, 411=>4.0});
+        assert_eq!(m.values().fold(0.0, |a, v| a + v), 28.0);
+        assert_eq!(g.max_exposure(&m), 36.0 + 4.0 * 36.0 + 36.0 + 4.0 * 36.0 - 28.0);
+        let m = g.expand_compound_bet(CompoundBet::Complete(0), 1.0).unwrap();
+        assert_eq!(m, hashmap!{100=>1.0, 200=>2.0, 201=>2.0, 202=>2.0, 300=>3.0, 301=>3.0, 422=>4.0});
+        assert_eq!(g.expand_compound_bet(CompoundBet::Complete(37), 1.0), None);
+    }
+
+    #[test]
+    fn test_catalogue(){
+        use games::dice::roulette::catalogue::{BoardPosition, Kind};
+
+        let g = Roulette::new();
+        let c = g.catalogue();
+        assert_eq!(c.len(), g.all_bets.len());
+        assert_eq!(c[0].id, 1);
+        assert_eq!(c[0].name, "Red");
+        assert_eq!(c[0].numbers.len(), 18);
+        assert_eq!(c[0].position, BoardPosition { x: 5.0, y: -1.5 });
+
+        let e = g.catalogue_entry(236).unwrap();
+        assert_eq!(e.kind, Kind::Split);
+        assert_eq!(e.name, "Split 1/2");
+        assert_eq!(e.numbers, vec![1, 2]);
+        assert_eq!(e.ratio, 17.0);
+        assert_eq!(e.position, BoardPosition { x: 0.5, y: 1.0 });
+
+        let e = g.catalogue_entry(422).unwrap();
+        assert_eq!(e.kind, Kind::Corner);
+        assert_eq!(e.name, "Corner 0-1-2-3");
+        assert_eq!(e.ratio, 8.0);
+
+        let e = g.catalogue_entry(600).unwrap();
+        assert_eq!(e.kind, Kind::Line);
+        assert_eq!(e.name, "Line 1-2-3-4-5-6");
+        assert_eq!(e.position, BoardPosition { x: 1.0, y: 0.0 });
+
+        let e = g.catalogue_entry(8).unwrap();
+        assert_eq!((e.kind, e.name.as_str(), e.ratio), (Kind::Dozen, "2nd Dozen", 2.0));
+        assert_eq!(e.numbers, (13..25).collect::<Vec<u8>>());
+        let e = g.catalogue_entry(12).unwrap();
+        assert_eq!((e.kind, e.name.as_str()), (Kind::Column, "3rd Column"));
+        assert_eq!(e.position, BoardPosition { x: 12.5, y: 2.5 });
+        assert_eq!(g.catalogue_entry(137), None);
+
+        let g = Roulette::with_wheel(Wheel::American);
+        let e = g.catalogue_entry(137).unwrap();
+        assert_eq!((e.kind, e.name.as_str(), e.numbers.clone()), (Kind::Straight, "Straight 00", vec![DOUBLE_ZERO]));
+        let e = g.catalogue_entry(500).unwrap();
+        assert_eq!((e.kind, e.name.as_str(), e.ratio), (Kind::Five, "Five 0-1-2-3-00", 6.0));
+        assert_eq!(g.catalogue_entry(262).unwrap().position, BoardPosition { x: -0.5, y: 1.5 });
+        // the 00-2-3 trio sits at its centroid like the other zero trios
+        let e = g.catalogue_entry(314).unwrap();
+        assert_eq!((e.kind, e.numbers.clone()), (Kind::Street, vec![2, 3, DOUBLE_ZERO]));
+        assert!((e.position.x - 0.5 / 3.0).abs() < 1e-12);
+        assert!((e.position.y - 4.75 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_multi_ball_payout_map(){
+        let g = Roulette::new();
+        assert_eq!(g.multi_ball_payout_map(&[17]), g.payout_map(17));
+        let m = g.multi_ball_payout_map(&[17, 17, 20]);
+        assert_eq!(m[&117], 71.0);
+        assert_eq!(m[&219], 52.0);
+        assert_eq!(m[&2], 4.0);
+        assert_eq!(m[&8], 7.0);
+        assert_eq!(m.get(&120), Some(&36.0));
+        assert_eq!(m.get(&1), None);
+    }
+
+    #[test]
+    fn test_double_ball_payout_map(){
+        let g = Roulette::new();
+        let m = g.double_ball_payout_map(0, 0);
+        assert_eq!(m[&100], 71.0);
+        assert_eq!(m[&700], 36.0);
+        assert_eq!(m[&701], 1301.0);
+        assert_eq!(m.get(&702), None);
+        let m = g.double_ball_payout_map(1, 3);
+        assert_eq!(m[&702], 4.0);
+        assert_eq!(m[&704], 4.0);
+        assert_eq!(m[&707], 4.0);
+        assert_eq!(m.get(&700), None);
+        assert_eq!(m.get(&708), None);
+        assert_eq!(g.double_ball_payout_map(1, 2)[&708], 2.0);
+        assert!(g.valid_double_ball_bets(708));
+        assert!(!g.valid_bets(708));
+
+        // 1296 / 1369 for the even money pairs
+        let mut rtp = HashMap::<u16, f64>::new();
+        for a in 0..37 {
+            for b in 0..37 {
+                for (id, v) in g.double_ball_payout_map(a, b) {
+                    *rtp.entry(id).or_insert(0.0) += v / 1369.0;
+                }
+            }
+        }
+        assert!((rtp[&700] - 36.0 / 37.0).abs() < 1e-9);
+        assert!((rtp[&701] - 1301.0 / 1369.0).abs() < 1e-9);
+        for id in 702..709 {
+            assert!((rtp[&id] - 1296.0 / 1369.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_lightning_payout_map(){
+        let g = Roulette::new();
+        let draw = LightningDraw(hashmap!{17=>200, 5=>50});
+        let m = g.lightning_payout_map(17, &draw);
+        assert_eq!(m[&117], 201.0);
+        assert_eq!(m[&216], 18.0);
+        assert_eq!(m.len(), g.payout_map(17).len());
+        assert_eq!(g.lightning_payout_map(18, &draw)[&118], 30.0);
+        assert_eq!(g.lightning_payout_map(5, &draw)[&105], 51.0);
+        let g = Roulette::with_wheel(Wheel::American);
+        let draw = LightningDraw(hashmap!{DOUBLE_ZERO=>500});
+        assert_eq!(g.lightning_payout_map(DOUBLE_ZERO, &draw)[&137], 501.0);
+    }
+
     #[test]
     fn test_payout_map_1(){
         let g = Roulette::new();
@@ -260,4 +762,13 @@ mod tests {
         let r = Roulette::new().payout_map(36);
         assert_eq!(r, hashmap!{9=>3.0,5=>2.0,235=>18.0,421=>9.0,12=>3.0,610=>6.0,313=>12.0,136=>36.0,1=>2.0,259=>18.0,4=>2.0});
     }      
+
+    #[test]
+    fn test_spin() {
+        let mut src = ::rand::thread_rng();
+        let g = Roulette::with_wheel(Wheel::American);
+        for _ in 0..1000 {
+            assert!(g.spin(&mut src) <= DOUBLE_ZERO);
+        }
+    }
 }
@@ -0,0 +1,135 @@
+use super::{Wheel, DOUBLE_ZERO};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Kind {
+    Straight,
+    Split,
+    Street,
+    Corner,
+    Five,
+    Line,
+    Dozen,
+    Column,
+    Simple,
+}
+
+// bet ids are grouped by kind in hundreds, dozens and columns share the simple range.
+pub fn kind(bet_id: u16) -> Option<Kind> {
+    match bet_id {
+        1..=6 => Some(Kind::Simple),
+        7..=9 => Some(Kind::Dozen),
+        10..=12 => Some(Kind::Column),
+        100..=199 => Some(Kind::Straight),
+        200..=299 => Some(Kind::Split),
+        300..=399 => Some(Kind::Street),
+        400..=499 => Some(Kind::Corner),
+        500..=599 => Some(Kind::Five),
+        600..=699 => Some(Kind::Line),
+        _ => None,
+    }
+}
+
+// Where the chip sits on the layout, in number cells. Numbers run along x in
+// streets of three, 1 is at (0.5, 0.5) and 36 at (11.5, 2.5); the zeros are
+// left of the layout at negative x, dozens and even money bets below it at
+// negative y and the columns at the right end.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BoardPosition {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatalogueEntry {
+    pub id: u16,
+    pub kind: Kind,
+    pub name: String,
+    pub numbers: Vec<u8>,
+    pub ratio: f64,
+    pub position: BoardPosition,
+}
+
+pub fn number_name(n: u8) -> String {
+    if n == DOUBLE_ZERO {
+        "00".to_string()
+    } else {
+        n.to_string()
+    }
+}
+
+fn cell(n: u8, wheel: Wheel) -> (f64, f64) {
+    match (n, wheel) {
+        (0, Wheel::European) => (-0.5, 1.5),
+        (0, Wheel::American) => (-0.5, 2.25),
+        (DOUBLE_ZERO, _) => (-0.5, 0.75),
+        _ => (f64::from((n - 1) / 3) + 0.5, f64::from((n - 1) % 3) + 0.5),
+    }
+}
+
+fn ordinal(i: u16) -> &'static str {
+    match i {
+        1 => "1st",
+        2 => "2nd",
+        _ => "3rd",
+    }
+}
+
+pub fn entry(id: u16, numbers: Vec<u8>, ratio: f64, wheel: Wheel) -> Option<CatalogueEntry> {
+    let kind = kind(id)?;
+    let joined = |sep: &str| {
+        numbers
+            .iter()
+            .map(|&n| number_name(n))
+            .collect::<Vec<String>>()
+            .join(sep)
+    };
+    let name = match kind {
+        Kind::Simple => ["Red", "Black", "Odd", "Even", "High (19-36)", "Low (1-18)"][id as usize - 1].to_string(),
+        Kind::Dozen => format!("{} Dozen", ordinal(id - 6)),
+        Kind::Column => format!("{} Column", ordinal(id - 9)),
+        Kind::Straight => format!("Straight {}", joined("")),
+        Kind::Split => format!("Split {}", joined("/")),
+        Kind::Street => format!("Street {}", joined("-")),
+        Kind::Corner => format!("Corner {}", joined("-")),
+        Kind::Five => format!("Five {}", joined("-")),
+        Kind::Line => format!("Line {}", joined("-")),
+    };
+    let (x, y) = numbers.iter().fold((0.0, 0.0), |(x, y), &n| {
+        let (cx, cy) = cell(n, wheel);
+        (x + cx, y + cy)
+    });
+    let count = numbers.len() as f64;
+    let centre = (x / count, y / count);
+    let position = match kind {
+        // even money boxes from left: low, even, red, black, odd, high
+        Kind::Simple => BoardPosition {
+            x: [5.0, 7.0, 9.0, 3.0, 11.0, 1.0][id as usize - 1],
+            y: -1.5,
+        },
+        Kind::Dozen => BoardPosition {
+            x: f64::from(id - 7) * 4.0 + 2.0,
+            y: -0.5,
+        },
+        Kind::Column => BoardPosition {
+            x: 12.5,
+            y: f64::from(id - 10) + 0.5,
+        },
+        // streets and lines are played on the edge of the layout, below the 1st column
+        Kind::Street | Kind::Line if !numbers.contains(&0) && !numbers.contains(&DOUBLE_ZERO) => BoardPosition {
+            x: centre.0,
+            y: 0.0,
+        },
+        _ => BoardPosition {
+            x: centre.0,
+            y: centre.1,
+        },
+    };
+    Some(CatalogueEntry {
+        id,
+        kind,
+        name,
+        numbers,
+        ratio,
+        position,
+    })
+}
@@ -0,0 +1,15 @@
+// compound bets made of several inside bets
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CompoundBet {
+    // straight up on every number ending with the digit
+    FinalePlein(u8),
+    // splits on the numbers ending with the two digits, e.g. 0/3 is 0-3, 10-13, 20-23, 30-33
+    FinaleCheval(u8, u8),
+    // every straight, split, street and corner touching the number, one chip per covered number
+    Complete(u8),
+}
+
+// numbers of the layout ending with the digit
+pub fn finals(digit: u8) -> Vec<u8> {
+    (0..4).map(|t| t * 10 + digit).filter(|&n| n <= 36).collect()
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use games::dice::{BetId, Ratio};
+use super::simple::{BLACK, EVEN, HIGH, LOW, ODD, RED};
+
+// bets settled on the pair of balls of a double ball round
+pub trait DoubleBetKind: BetId + Ratio {
+    fn bingo(&self, a: u8, b: u8) -> bool;
+}
+
+// both balls on the same number
+struct SameNumber(u16);
+
+impl DoubleBetKind for SameNumber {
+    fn bingo(&self, a: u8, b: u8) -> bool {
+        a == b
+    }
+}
+
+impl Ratio for SameNumber {
+    fn ratio(&self) -> f64 {
+        35.0
+    }
+}
+
+impl BetId for SameNumber {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+struct BothZero(u16);
+
+impl DoubleBetKind for BothZero {
+    fn bingo(&self, a: u8, b: u8) -> bool {
+        a == 0 && b == 0
+    }
+}
+
+impl Ratio for BothZero {
+    fn ratio(&self) -> f64 {
+        1300.0
+    }
+}
+
+impl BetId for BothZero {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+// both balls in the same even money group
+struct Both18(u16, [u8; 18]);
+
+impl DoubleBetKind for Both18 {
+    fn bingo(&self, a: u8, b: u8) -> bool {
+        self.1.contains(&a) && self.1.contains(&b)
+    }
+}
+
+impl Ratio for Both18 {
+    fn ratio(&self) -> f64 {
+        3.0
+    }
+}
+
+impl BetId for Both18 {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+// one ball red and the other black
+struct RedBlack(u16);
+
+impl DoubleBetKind for RedBlack {
+    fn bingo(&self, a: u8, b: u8) -> bool {
+        (RED.contains(&a) && BLACK.contains(&b)) || (BLACK.contains(&a) && RED.contains(&b))
+    }
+}
+
+impl Ratio for RedBlack {
+    fn ratio(&self) -> f64 {
+        1.0
+    }
+}
+
+impl BetId for RedBlack {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+fn add(b: Box<DoubleBetKind>, map: &mut HashMap<u16, Box<DoubleBetKind>>) {
+    map.insert(b.id(), b);
+}
+
+pub fn all_bets(map: &mut HashMap<u16, Box<DoubleBetKind>>) {
+    add(Box::new(SameNumber(700)), map);
+    add(Box::new(BothZero(701)), map);
+    //both red
+    add(Box::new(Both18(702, RED)), map);
+    //both black
+    add(Box::new(Both18(703, BLACK)), map);
+    //both odd
+    add(Box::new(Both18(704, ODD)), map);
+    //both even
+    add(Box::new(Both18(705, EVEN)), map);
+    //both high
+    add(Box::new(Both18(706, HIGH)), map);
+    //both low
+    add(Box::new(Both18(707, LOW)), map);
+    add(Box::new(RedBlack(708)), map);
+}
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+use games::dice::{BetId, Ratio};
+use super::{add, BetKind, DOUBLE_ZERO};
+
+#[derive(Clone, Copy)]
+struct Five(u16, [u8; 5]);
+
+impl BetKind for Five {
+    fn bingo(&self, num: u8) -> bool {
+        self.1.contains(&num)
+    }
+}
+
+impl Ratio for Five {
+    fn ratio(&self) -> f64 {
+        6.0
+    }
+}
+
+impl BetId for Five {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+// top line of the double zero wheel
+pub fn american_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    add(Box::new(Five(500, [0, DOUBLE_ZERO, 1, 2, 3])), map);
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use games::rng::{weighted, RandomSource};
+
+// straight ups pay 29:1 unless their number is struck by a multiplier.
+pub const LIGHTNING_STRAIGHT_RATIO: f64 = 29.0;
+
+// weighted distributions of how many numbers are struck each round and of
+// the multiplier each of them receives.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LightningConfig {
+    pub counts: Vec<(u8, u32)>,
+    pub multipliers: Vec<(u16, u32)>,
+}
+
+// multiplier per struck number for one round
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct LightningDraw(pub HashMap<u8, u16>);
+
+impl LightningConfig {
+    pub fn new(counts: Vec<(u8, u32)>, multipliers: Vec<(u16, u32)>) -> LightningConfig {
+        LightningConfig {
+            counts,
+            multipliers,
+        }
+    }
+
+    // 1 to 5 numbers, 50x to 500x, 97.30% on a single zero wheel
+    pub fn standard() -> LightningConfig {
+        LightningConfig::new(
+            vec![(1, 45), (2, 25), (3, 20), (4, 5), (5, 5)],
+            vec![(50, 40), (100, 25), (200, 20), (300, 8), (400, 4), (500, 3)],
+        )
+    }
+
+    pub fn draw<R: RandomSource>(&self, src: &mut R, pockets: u8) -> LightningDraw {
+        let count = weighted(&self.counts, src);
+        let mut numbers: Vec<u8> = (0..pockets).collect();
+        let mut draw = HashMap::new();
+        for i in 0..(count as usize).min(numbers.len()) {
+            // partial Fisher-Yates, the struck numbers are distinct
+            let j = i + src.below((numbers.len() - i) as u32) as usize;
+            numbers.swap(i, j);
+            draw.insert(numbers[i], weighted(&self.multipliers, src));
+        }
+        LightningDraw(draw)
+    }
+
+    pub fn expected_count(&self) -> f64 {
+        expected(&self.counts)
+    }
+
+    pub fn expected_multiplier(&self) -> f64 {
+        expected(&self.multipliers)
+    }
+
+    // return to player of a straight up, the struck number is uniform over the wheel.
+    pub fn straight_rtp(&self, pockets: u8) -> f64 {
+        let n = f64::from(pockets);
+        let struck = self.expected_count().min(n) / n;
+        ((1.0 - struck) * (LIGHTNING_STRAIGHT_RATIO + 1.0) + struck * (self.expected_multiplier() + 1.0)) / n
+    }
+}
+
+fn expected<T: Copy + Into<f64>>(items: &[(T, u32)]) -> f64 {
+    let total = items.iter().fold(0, |a, &(_, w)| a + w);
+    items
+        .iter()
+        .fold(0.0, |a, &(v, w)| a + v.into() * f64::from(w))
+        / f64::from(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::rng::fair::ProvablyFair;
+
+    #[test]
+    fn test_standard_rtp() {
+        let c = LightningConfig::standard();
+        assert_eq!(c.expected_count(), 2.0);
+        assert_eq!(c.expected_multiplier(), 140.0);
+        assert!((c.straight_rtp(37) - 36.0 / 37.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_draw() {
+        let c = LightningConfig::standard();
+        let mut src = ProvablyFair::new("server", "client", 1);
+        let rounds = 20000;
+        let mut struck = 0;
+        let mut total = 0.0;
+        for _ in 0..rounds {
+            let d = c.draw(&mut src, 37);
+            assert!(d.0.len() >= 1 && d.0.len() <= 5);
+            for (&n, &m) in &d.0 {
+                assert!(n < 37);
+                assert!(m >= 50 && m <= 500);
+                struck += 1;
+                total += f64::from(m);
+            }
+        }
+        assert!((f64::from(struck) / f64::from(rounds) - 2.0).abs() < 0.05);
+        assert!((total / f64::from(struck) - 140.0).abs() < 5.0);
+    }
+}
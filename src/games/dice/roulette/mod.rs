@@ -3,15 +3,45 @@ pub mod split;
 pub mod street;
 pub mod corner;
 pub mod line;
+pub mod five;
 pub mod simple;
+pub mod racetrack;
+pub mod compound;
+pub mod lightning;
+pub mod double;
+pub mod stats;
+pub mod catalogue;
 
 pub mod all;
 
 use std::collections::HashMap;
 use super::{BetId, Ratio};
 
+// the ball number of 00 on the double zero wheel
+pub const DOUBLE_ZERO: u8 = 37;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Wheel {
+    European,
+    American,
+}
+
+// French rules for even money bets when the ball lands on zero: La Partage
+// returns half the stake, En Prison keeps the bet for the next spin.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EvenMoney {
+    Standard,
+    LaPartage,
+    EnPrison,
+}
+
 pub trait BetKind: BetId + Ratio {
     fn bingo(&self, d: u8) -> bool;
+
+    // number of balls the bet wins on, for multi ball rounds
+    fn hits(&self, balls: &[u8]) -> u8 {
+        balls.iter().filter(|&&b| self.bingo(b)).count() as u8
+    }
 }
 
 fn add(b: Box<BetKind>, map: &mut HashMap<u16, Box<BetKind>>)
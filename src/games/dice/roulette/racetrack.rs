@@ -0,0 +1,99 @@
+use games::dice::roulette::DOUBLE_ZERO;
+
+pub static EUROPEAN_WHEEL: &[u8] = &[
+    0, 32, 15, 19, 4, 21, 2, 25, 17, 34, 6, 27, 13, 36, 11, 30, 8, 23, 10, 5, 24, 16, 33, 1, 20,
+    14, 31, 9, 22, 18, 29, 7, 28, 12, 35, 3, 26,
+];
+
+pub static AMERICAN_WHEEL: &[u8] = &[
+    0, 28, 9, 26, 30, 11, 7, 20, 32, 17, 5, 22, 34, 15, 3, 24, 36, 13, 1, DOUBLE_ZERO, 27, 10, 25,
+    29, 12, 8, 19, 31, 18, 6, 21, 33, 16, 4, 23, 35, 14, 2,
+];
+
+// announced bets of the racetrack
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CallBet {
+    Voisins,
+    Tiers,
+    Orphelins,
+    JeuZero,
+    // the number and n neighbours on each side of it on the wheel
+    Neighbours(u8, u8),
+}
+
+impl CallBet {
+    // bet ids with the number of chips placed on them.
+    pub fn chips(&self, wheel: &[u8]) -> Option<Vec<(u16, u8)>> {
+        match *self {
+            CallBet::Voisins => Some(vec![
+                (301, 2),
+                (206, 1),
+                (214, 1),
+                (220, 1),
+                (221, 1),
+                (234, 1),
+                (416, 2),
+            ]),
+            CallBet::Tiers => Some(vec![
+                (207, 1),
+                (242, 1),
+                (215, 1),
+                (251, 1),
+                (229, 1),
+                (235, 1),
+            ]),
+            CallBet::Orphelins => Some(vec![(101, 1), (208, 1), (216, 1), (219, 1), (233, 1)]),
+            CallBet::JeuZero => Some(vec![(202, 1), (214, 1), (126, 1), (234, 1)]),
+            CallBet::Neighbours(x, n) => neighbours(wheel, x, n)
+                .map(|v| v.iter().map(|&num| (100 + u16::from(num), 1)).collect()),
+        }
+    }
+}
+
+pub fn neighbours(wheel: &[u8], x: u8, n: u8) -> Option<Vec<u8>> {
+    let l = wheel.len();
+    let n = n as usize;
+    if 2 * n + 1 > l {
+        return None;
+    }
+    wheel.iter().position(|&v| v == x).map(|p| {
+        (0..2 * n + 1)
+            .map(|i| wheel[(p + l + i - n) % l])
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wheels() {
+        let mut v = EUROPEAN_WHEEL.to_vec();
+        v.sort();
+        assert_eq!(v, (0..37).collect::<Vec<u8>>());
+        let mut v = AMERICAN_WHEEL.to_vec();
+        v.sort();
+        assert_eq!(v, (0..38).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(neighbours(EUROPEAN_WHEEL, 0, 2), Some(vec![3, 26, 0, 32, 15]));
+        assert_eq!(neighbours(EUROPEAN_WHEEL, 26, 1), Some(vec![3, 26, 0]));
+        assert_eq!(neighbours(EUROPEAN_WHEEL, 17, 0), Some(vec![17]));
+        assert_eq!(neighbours(EUROPEAN_WHEEL, 37, 1), None);
+        assert_eq!(neighbours(EUROPEAN_WHEEL, 1, 19), None);
+        assert_eq!(neighbours(AMERICAN_WHEEL, 1, 1), Some(vec![13, 1, DOUBLE_ZERO]));
+    }
+
+    #[test]
+    fn test_chips() {
+        let count = |c: CallBet| c.chips(EUROPEAN_WHEEL).unwrap().iter().fold(0, |a, &(_, n)| a + n);
+        assert_eq!(count(CallBet::Voisins), 9);
+        assert_eq!(count(CallBet::Tiers), 6);
+        assert_eq!(count(CallBet::Orphelins), 5);
+        assert_eq!(count(CallBet::JeuZero), 4);
+        assert_eq!(count(CallBet::Neighbours(5, 2)), 5);
+    }
+}
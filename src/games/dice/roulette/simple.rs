@@ -2,6 +2,13 @@ use std::collections::HashMap;
 use games::dice::{BetId, Ratio};
 use super::{add, BetKind};
 
+pub const RED: [u8; 18] = [1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36];
+pub const BLACK: [u8; 18] = [2, 4, 6, 8, 10, 11, 13, 15, 17, 20, 22, 24, 26, 28, 29, 31, 33, 35];
+pub const ODD: [u8; 18] = [1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35];
+pub const EVEN: [u8; 18] = [2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36];
+pub const HIGH: [u8; 18] = [19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36];
+pub const LOW: [u8; 18] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18];
+
 struct Simple18(u16, [u8; 18]);
 
 impl Ratio for Simple18 {
@@ -44,17 +51,17 @@ impl BetId for Simple12 {
 
 pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>) {
     //red
-    add(Box::new(Simple18(1, [1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36])),map);
+    add(Box::new(Simple18(1, RED)),map);
     //black
-    add(Box::new(Simple18(2, [2, 4, 6, 8, 10, 11, 13, 15, 17, 20, 22, 24, 26, 28, 29, 31, 33, 35])),map);
+    add(Box::new(Simple18(2, BLACK)),map);
     //odd
-    add(Box::new(Simple18(3, [1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35])),map);
+    add(Box::new(Simple18(3, ODD)),map);
     //even
-    add(Box::new(Simple18(4, [2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36])),map);
+    add(Box::new(Simple18(4, EVEN)),map);
     //high
-    add(Box::new(Simple18(5, [19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36])),map);
+    add(Box::new(Simple18(5, HIGH)),map);
     //low
-    add(Box::new(Simple18(6, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18])),map);
+    add(Box::new(Simple18(6, LOW)),map);
     
     //1st dozen
     add(Box::new(Simple12(7, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])),map);
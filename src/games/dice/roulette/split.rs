@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use games::dice::{Ratio,BetId};
-use super::{add, BetKind};
+use super::{add, BetKind, DOUBLE_ZERO};
 
 #[derive(Clone,Copy)]
 struct Split(u16, u8, u8);
@@ -85,3 +85,9 @@ pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>){
     add(Box::new(Split(258, 34, 35)), map);
     add(Box::new(Split(259, 35, 36)), map);
 }
+
+pub fn american_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    add(Box::new(Split(260, DOUBLE_ZERO, 2)), map);
+    add(Box::new(Split(261, DOUBLE_ZERO, 3)), map);
+    add(Box::new(Split(262, 0, DOUBLE_ZERO)), map);
+}
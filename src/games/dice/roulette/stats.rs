@@ -0,0 +1,260 @@
+use std::collections::VecDeque;
+use super::simple::{BLACK, RED};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Colour {
+    Red,
+    Black,
+    Green,
+}
+
+pub fn colour(ball: u8) -> Colour {
+    if RED.contains(&ball) {
+        Colour::Red
+    } else if BLACK.contains(&ball) {
+        Colour::Black
+    } else {
+        Colour::Green
+    }
+}
+
+// 0, 1, 2 for the 1st, 2nd and 3rd dozen, None for the zeros
+pub fn dozen(ball: u8) -> Option<usize> {
+    match ball {
+        1..=36 => Some((ball as usize - 1) / 12),
+        _ => None,
+    }
+}
+
+pub fn column(ball: u8) -> Option<usize> {
+    match ball {
+        1..=36 => Some((ball as usize - 1) % 3),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Streak {
+    pub colour: Colour,
+    pub length: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatsSnapshot {
+    pub spins: u64,
+    pub window: usize,
+    pub last: Vec<u8>,
+    // (number, hits in the window), most frequent first
+    pub hot: Vec<(u8, usize)>,
+    // least frequent first
+    pub cold: Vec<(u8, usize)>,
+    pub red: f64,
+    pub black: f64,
+    pub green: f64,
+    pub dozens: [f64; 3],
+    pub columns: [f64; 3],
+    pub current_streak: Option<Streak>,
+    pub longest_streak: Option<Streak>,
+    // spins since each number last came up, indexed by ball
+    pub sleeping: Vec<u64>,
+}
+
+// results of the last `window` spins of a table, sleeping counts cover every spin.
+pub struct WheelStats {
+    pockets: u8,
+    window: usize,
+    spins: u64,
+    history: VecDeque<u8>,
+    last_seen: Vec<Option<u64>>,
+}
+
+impl WheelStats {
+    pub fn new(pockets: u8, window: usize) -> WheelStats {
+        WheelStats {
+            pockets,
+            window,
+            spins: 0,
+            history: VecDeque::with_capacity(window),
+            last_seen: vec![None; pockets as usize],
+        }
+    }
+
+    pub fn add(&mut self, ball: u8) {
+        if ball >= self.pockets {
+            return;
+        }
+        // a window of 0 keeps no history, only the sleeping counts
+        if self.window > 0 {
+            if self.history.len() == self.window {
+                self.history.pop_front();
+            }
+            self.history.push_back(ball);
+        }
+        self.last_seen[ball as usize] = Some(self.spins);
+        self.spins += 1;
+    }
+
+    pub fn spins(&self) -> u64 {
+        self.spins
+    }
+
+    pub fn frequencies(&self) -> Vec<usize> {
+        let mut f = vec![0; self.pockets as usize];
+        for &b in &self.history {
+            f[b as usize] += 1;
+        }
+        f
+    }
+
+    pub fn sleeping(&self, ball: u8) -> u64 {
+        match self.last_seen[ball as usize] {
+            Some(s) => self.spins - s - 1,
+            None => self.spins,
+        }
+    }
+
+    // the run of one colour ending with the last spin
+    pub fn current_streak(&self) -> Option<Streak> {
+        let c = colour(*self.history.back()?);
+        let length = self.history.iter().rev().take_while(|&&b| colour(b) == c).count();
+        Some(Streak { colour: c, length })
+    }
+
+    pub fn longest_streak(&self) -> Option<Streak> {
+        let mut best: Option<Streak> = None;
+        let mut current: Option<Streak> = None;
+        for &b in &self.history {
+            let c = colour(b);
+            current = match current {
+                Some(ref s) if s.colour == c => Some(Streak {
+                    colour: c,
+                    length: s.length + 1,
+                }),
+                _ => Some(Streak { colour: c, length: 1 }),
+            };
+            if best.as_ref().map(|s| s.length) < current.as_ref().map(|s| s.length) {
+                best = current.clone();
+            }
+        }
+        best
+    }
+
+    pub fn snapshot(&self, n: usize) -> StatsSnapshot {
+        let f = self.frequencies();
+        let mut ranked: Vec<(u8, usize)> = f.iter().enumerate().map(|(b, &c)| (b as u8, c)).collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        let hot = ranked.iter().take(n).cloned().collect();
+        ranked.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+        let cold = ranked.iter().take(n).cloned().collect();
+
+        let total = self.history.len() as f64;
+        let share = |count: usize| if total > 0.0 { count as f64 / total } else { 0.0 };
+        let count_colour = |c| self.history.iter().filter(|&&b| colour(b) == c).count();
+        let mut dozens = [0; 3];
+        let mut columns = [0; 3];
+        for &b in &self.history {
+            if let Some(d) = dozen(b) {
+                dozens[d] += 1;
+            }
+            if let Some(c) = column(b) {
+                columns[c] += 1;
+            }
+        }
+        StatsSnapshot {
+            spins: self.spins,
+            window: self.window,
+            last: self.history.iter().rev().cloned().collect(),
+            hot,
+            cold,
+            red: share(count_colour(Colour::Red)),
+            black: share(count_colour(Colour::Black)),
+            green: share(count_colour(Colour::Green)),
+            dozens: [share(dozens[0]), share(dozens[1]), share(dozens[2])],
+            columns: [share(columns[0]), share(columns[1]), share(columns[2])],
+            current_streak: self.current_streak(),
+            longest_streak: self.longest_streak(),
+            sleeping: (0..self.pockets).map(|b| self.sleeping(b)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(balls: &[u8]) -> WheelStats {
+        let mut s = WheelStats::new(37, 8);
+        for &b in balls {
+            s.add(b);
+        }
+        s
+    }
+
+    #[test]
+    fn test_colour() {
+        assert_eq!(colour(0), Colour::Green);
+        assert_eq!(colour(37), Colour::Green);
+        assert_eq!(colour(1), Colour::Red);
+        assert_eq!(colour(2), Colour::Black);
+        assert_eq!(dozen(12), Some(0));
+        assert_eq!(dozen(13), Some(1));
+        assert_eq!(column(34), Some(0));
+        assert_eq!(column(36), Some(2));
+        assert_eq!(column(0), None);
+    }
+
+    #[test]
+    fn test_window() {
+        let s = stats(&[5, 5, 5, 1, 3, 0, 17, 17, 20, 17]);
+        assert_eq!(s.spins(), 10);
+        let f = s.frequencies();
+        assert_eq!(f[5], 1);
+        assert_eq!(f[17], 3);
+        assert_eq!(f.iter().sum::<usize>(), 8);
+        assert_eq!(s.sleeping(17), 0);
+        assert_eq!(s.sleeping(5), 7);
+        assert_eq!(s.sleeping(36), 10);
+        assert_eq!(stats(&[37]).spins(), 0);
+    }
+
+    #[test]
+    fn test_zero_window() {
+        let mut s = WheelStats::new(37, 0);
+        for b in 0..20 {
+            s.add(b);
+        }
+        assert_eq!(s.spins(), 20);
+        assert_eq!(s.frequencies().iter().sum::<usize>(), 0);
+        assert_eq!(s.sleeping(0), 19);
+        assert_eq!(s.current_streak(), None);
+        assert!(s.snapshot(3).last.is_empty());
+    }
+
+    #[test]
+    fn test_streaks() {
+        let s = stats(&[1, 3, 5, 0, 2, 4]);
+        assert_eq!(s.current_streak(), Some(Streak { colour: Colour::Black, length: 2 }));
+        assert_eq!(s.longest_streak(), Some(Streak { colour: Colour::Red, length: 3 }));
+        assert_eq!(stats(&[]).current_streak(), None);
+        assert_eq!(stats(&[]).longest_streak(), None);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let s = stats(&[1, 3, 5, 0, 17, 17, 20, 36]).snapshot(2);
+        assert_eq!(s.last, vec![36, 20, 17, 17, 0, 5, 3, 1]);
+        assert_eq!(s.hot, vec![(17, 2), (0, 1)]);
+        assert_eq!(s.cold, vec![(2, 0), (4, 0)]);
+        assert_eq!(s.red, 0.5);
+        assert_eq!(s.black, 0.375);
+        assert_eq!(s.green, 0.125);
+        assert_eq!(s.dozens, [0.375, 0.375, 0.125]);
+        assert_eq!(s.columns, [0.125, 0.5, 0.25]);
+        assert_eq!(s.sleeping[1], 7);
+        assert_eq!(s.sleeping.len(), 37);
+
+        let s = WheelStats::new(37, 10).snapshot(3);
+        assert_eq!(s.red, 0.0);
+        assert_eq!(s.hot, vec![(0, 0), (1, 0), (2, 0)]);
+    }
+}
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use super::{add, BetKind};
+use super::{add, BetKind, DOUBLE_ZERO};
 use games::dice::{BetId, Ratio};
 
 #[derive(Clone, Copy)]
@@ -62,3 +62,7 @@ pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>) {
     add(Box::new(Straight(135, 35)), map);
     add(Box::new(Straight(136, 36)), map);
 }
+
+pub fn american_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    add(Box::new(Straight(137, DOUBLE_ZERO)), map);
+}
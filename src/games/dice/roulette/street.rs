@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use games::dice::{BetId, Ratio};
-use super::{add, BetKind};
+use super::{add, BetKind, DOUBLE_ZERO};
 
 #[derive(Clone, Copy)]
 struct Street(u16, u8, u8, u8);
@@ -39,3 +39,8 @@ pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>) {
     add(Box::new(Street(312, 31, 32, 33)), map);
     add(Box::new(Street(313, 34, 35, 36)), map);
 }
+
+pub fn american_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    add(Box::new(Street(314, DOUBLE_ZERO, 2, 3)), map);
+    add(Box::new(Street(315, 0, DOUBLE_ZERO, 2)), map);
+}
@@ -1,12 +1,21 @@
 use std::collections::HashMap;
+use super::boost::BoostConfig;
+use super::catalogue::{self, CatalogueEntry};
 use super::{double, pair, simple, single, total, triple, BetKind, Result};
+use games::rng::{self, RandomSource};
 
 pub struct Sicbo {
     all_bets: HashMap<u16, Box<BetKind>>,
+    paytable: HashMap<u16, f64>,
 }
 
 impl Sicbo {
     pub fn new() -> Sicbo {
+        Sicbo::with_paytable(HashMap::new())
+    }
+
+    // ratios of the listed bet ids replace the classic paytable
+    pub fn with_paytable(paytable: HashMap<u16, f64>) -> Sicbo {
         let mut map = HashMap::<u16, Box<BetKind>>::new();
         simple::all_bets(&mut map);
         single::all_bets(&mut map);
@@ -14,24 +23,77 @@ impl Sicbo {
         triple::all_bets(&mut map);
         pair::all_bets(&mut map);
         total::all_bets(&mut map);
-        Sicbo { all_bets: map }
+        Sicbo {
+            all_bets: map,
+            paytable,
+        }
+    }
+
+    pub fn roll<R: RandomSource>(&self, src: &mut R) -> (u8, u8, u8) {
+        rng::roll_dice(src)
     }
 
     pub fn valid_bets(&self, bet_id: u16) -> bool {
         self.all_bets.contains_key(&bet_id)
     }
 
+    pub fn catalogue_entry(&self, bet_id: u16) -> Option<CatalogueEntry> {
+        let b = self.all_bets.get(&bet_id)?;
+        catalogue::entry(bet_id, b.ratio())
+    }
+
+    // every bet of the layout, ordered by id.
+    pub fn catalogue(&self) -> Vec<CatalogueEntry> {
+        let mut ids: Vec<u16> = self.all_bets.keys().cloned().collect();
+        ids.sort();
+        ids.into_iter().filter_map(|id| self.catalogue_entry(id)).collect()
+    }
+
     pub fn payout_map(&self, d1: u8, d2: u8, d3: u8) -> HashMap<u16, f64> {
+        self.boosted_payout_map(d1, d2, d3, &HashMap::new())
+    }
+
+    // a boosted bet pays its multiplier in place of the ratio.
+    pub fn boosted_payout_map(&self, d1: u8, d2: u8, d3: u8, boosts: &HashMap<u16, u16>) -> HashMap<u16, f64> {
+        let mut map = super::payout_map(&self.all_bets, d1, d2, d3);
         let r = Result::new(d1, d2, d3);
-        let mut map = HashMap::<u16, f64>::new();
-        for (&id, b) in &self.all_bets {
-            let c = b.bingo(&r);
-            if c > 0 {
-                map.insert(id, b.ratio() * (c as f64) + 1.0);
-            }
+        for (id, v) in &mut map {
+            let ratio = match (boosts.get(id), self.paytable.get(id)) {
+                (Some(&m), _) => f64::from(m),
+                (None, Some(&ratio)) => ratio,
+                (None, None) => continue,
+            };
+            *v = ratio * f64::from(self.all_bets[id].bingo(&r)) + 1.0;
         }
         map
     }
+
+    // Return to player of a bet over every roll, with the bet boosted by the
+    // expected multiplier of its spot as often as the config draws it. None
+    // when the base paytable of the config is not the one of this game.
+    pub fn rtp(&self, bet_id: u16, config: &BoostConfig) -> Option<f64> {
+        if !self.valid_bets(bet_id) || config.base != self.paytable {
+            return None;
+        }
+        let (p, m) = match config.spot(bet_id) {
+            Some(s) => (s.probability(), s.expected_multiplier()),
+            None => (0.0, 0.0),
+        };
+        let mut base = 0.0;
+        let mut boosted = 0.0;
+        for d1 in 1..7 {
+            for d2 in 1..7 {
+                for d3 in 1..7 {
+                    base += self.payout_map(d1, d2, d3).get(&bet_id).cloned().unwrap_or(0.0);
+                    let c = self.all_bets[&bet_id].bingo(&Result::new(d1, d2, d3));
+                    if c > 0 {
+                        boosted += m * f64::from(c) + 1.0;
+                    }
+                }
+            }
+        }
+        Some(((1.0 - p) * base + p * boosted) / 216.0)
+    }
 }
 
 
@@ -123,5 +185,82 @@ mod tests {
         assert_eq!(r, hashmap!(16=>2.0, 15=>3.0, 256=>6.0, 116=>19.0, 1=>2.0, 4=>2.0, 25=>9.0));
     }
 
+    #[test]
+    fn test_catalogue() {
+        use games::dice::sicbo::catalogue::{BoardPosition, Kind};
+
+        let g = Sicbo::new();
+        let c = g.catalogue();
+        assert_eq!(c.len(), 52);
+        assert_eq!(c[0].name, "Big");
+        assert_eq!(c[0].numbers, vec![11, 12, 13, 14, 15, 16, 17]);
+        assert_eq!(c[2].numbers, vec![5, 7, 9, 11, 13, 15, 17]);
+
+        let e = g.catalogue_entry(236).unwrap();
+        assert_eq!((e.kind, e.name.as_str(), e.ratio), (Kind::Pair, "Pair 3-6", 5.0));
+        assert_eq!(e.numbers, vec![3, 6]);
+        assert_eq!(e.position, BoardPosition { row: 2, col: 11 });
+
+        let e = g.catalogue_entry(104).unwrap();
+        assert_eq!((e.kind, e.name.as_str(), e.ratio), (Kind::Total, "Total 4", 50.0));
+        assert_eq!(e.position, BoardPosition { row: 1, col: 0 });
+
+        let e = g.catalogue_entry(37).unwrap();
+        assert_eq!((e.kind, e.ratio), (Kind::AnyTriple, 24.0));
+        assert_eq!(g.catalogue_entry(34).unwrap().position, BoardPosition { row: 0, col: 9 });
+        assert_eq!(g.catalogue_entry(24).unwrap().position, BoardPosition { row: 0, col: 12 });
+        assert_eq!(g.catalogue_entry(15).unwrap().position, BoardPosition { row: 3, col: 4 });
+        assert_eq!(g.catalogue_entry(5), None);
+    }
+
+    #[test]
+    fn test_boosted_payout_map() {
+        let g = Sicbo::with_paytable(hashmap!{104=>30.0, 31=>100.0});
+        assert_eq!(g.payout_map(1, 1, 2), hashmap!{104=>31.0, 11=>3.0, 12=>2.0, 21=>9.0, 212=>6.0, 2=>2.0, 4=>2.0});
+        assert_eq!(g.payout_map(1, 1, 1)[&31], 101.0);
+        let boosts = hashmap!{104=>200, 11=>5, 15=>50};
+        let r = g.boosted_payout_map(1, 1, 2, &boosts);
+        assert_eq!(r[&104], 201.0);
+        assert_eq!(r[&11], 11.0);
+        assert_eq!(r[&12], 2.0);
+        assert_eq!(r.get(&15), None);
+    }
 
+    #[test]
+    fn test_rtp() {
+        use games::dice::sicbo::boost::BoostSpot;
+
+        let g = Sicbo::new();
+        let none = BoostConfig::default();
+        assert!((g.rtp(31, &none).unwrap() - 151.0 / 216.0).abs() < 1e-12);
+        assert!((g.rtp(1, &none).unwrap() - 210.0 / 216.0).abs() < 1e-12);
+        assert!((g.rtp(11, &none).unwrap() - (75.0 * 2.0 + 15.0 * 3.0 + 4.0) / 216.0).abs() < 1e-12);
+        assert_eq!(g.rtp(5, &none), None);
+
+        let c = BoostConfig::new(
+            hashmap!{104=>30.0, 11=>1.0},
+            vec![
+                BoostSpot::new(104, 2500, vec![(60, 1), (100, 1)]),
+                BoostSpot::new(11, 1000, vec![(3, 1)]),
+            ],
+        );
+        let g = Sicbo::with_paytable(c.base.clone());
+        // 3 / 216 * (0.75 * 31 + 0.25 * 81)
+        assert!((g.rtp(104, &c).unwrap() - 130.5 / 216.0).abs() < 1e-12);
+        // 0.9 * 199 / 216 + 0.1 * (75 * 4 + 15 * 7 + 10) / 216
+        assert!((g.rtp(11, &c).unwrap() - (0.9 * 199.0 + 0.1 * 415.0) / 216.0).abs() < 1e-12);
+        assert!((g.rtp(105, &c).unwrap() - 6.0 * 19.0 / 216.0).abs() < 1e-12);
+        assert_eq!(Sicbo::new().rtp(104, &c), None);
+        assert_eq!(g.rtp(104, &none), None);
+    }
+
+    #[test]
+    fn test_roll() {
+        let mut src = ::rand::thread_rng();
+        let g = Sicbo::new();
+        for _ in 0..1000 {
+            let (d1, d2, d3) = g.roll(&mut src);
+            assert!(!g.payout_map(d1, d2, d3).is_empty());
+        }
+    }
 }
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use games::rng::{weighted, RandomSource};
+
+// chances are drawn out of this many rounds
+pub const CHANCE_BASE: u32 = 10_000;
+
+// a bet spot which may be boosted before the roll, with `chance` out of
+// CHANCE_BASE and a weighted distribution of multipliers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoostSpot {
+    pub bet_id: u16,
+    pub chance: u32,
+    pub multipliers: Vec<(u16, u32)>,
+}
+
+impl BoostSpot {
+    pub fn new(bet_id: u16, chance: u32, multipliers: Vec<(u16, u32)>) -> BoostSpot {
+        BoostSpot {
+            bet_id,
+            chance,
+            multipliers,
+        }
+    }
+
+    pub fn probability(&self) -> f64 {
+        f64::from(self.chance.min(CHANCE_BASE)) / f64::from(CHANCE_BASE)
+    }
+
+    pub fn expected_multiplier(&self) -> f64 {
+        let total = self.multipliers.iter().fold(0, |a, &(_, w)| a + w);
+        self.multipliers
+            .iter()
+            .fold(0.0, |a, &(m, w)| a + f64::from(m) * f64::from(w))
+            / f64::from(total)
+    }
+}
+
+// Super Sic Bo: the base paytable is reduced to fund the multipliers, `base`
+// overrides the ratio of the listed bet ids.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct BoostConfig {
+    pub base: HashMap<u16, f64>,
+    pub spots: Vec<BoostSpot>,
+}
+
+impl BoostConfig {
+    pub fn new(base: HashMap<u16, f64>, spots: Vec<BoostSpot>) -> BoostConfig {
+        BoostConfig { base, spots }
+    }
+
+    pub fn spot(&self, bet_id: u16) -> Option<&BoostSpot> {
+        self.spots.iter().find(|s| s.bet_id == bet_id)
+    }
+
+    // multiplier per boosted bet id for one round
+    pub fn draw<R: RandomSource>(&self, src: &mut R) -> HashMap<u16, u16> {
+        let mut boosts = HashMap::new();
+        for s in &self.spots {
+            if src.below(CHANCE_BASE) < s.chance {
+                boosts.insert(s.bet_id, weighted(&s.multipliers, src));
+            }
+        }
+        boosts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::rng::fair::ProvablyFair;
+
+    #[test]
+    fn test_draw() {
+        let c = BoostConfig::new(
+            HashMap::new(),
+            vec![
+                BoostSpot::new(104, 2500, vec![(60, 1), (100, 1)]),
+                BoostSpot::new(31, CHANCE_BASE, vec![(500, 1)]),
+                BoostSpot::new(21, 0, vec![(10, 1)]),
+            ],
+        );
+        assert_eq!(c.spot(104).unwrap().expected_multiplier(), 80.0);
+        assert_eq!(c.spot(104).unwrap().probability(), 0.25);
+        let mut src = ProvablyFair::new("server", "client", 1);
+        let rounds = 10000;
+        let mut boosted = 0;
+        for _ in 0..rounds {
+            let b = c.draw(&mut src);
+            assert_eq!(b[&31], 500);
+            assert!(!b.contains_key(&21));
+            if let Some(&m) = b.get(&104) {
+                assert!(m == 60 || m == 100);
+                boosted += 1;
+            }
+        }
+        assert!((f64::from(boosted) / f64::from(rounds) - 0.25).abs() < 0.02);
+    }
+}
@@ -0,0 +1,92 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Kind {
+    Simple,
+    Single,
+    Double,
+    Triple,
+    AnyTriple,
+    Total,
+    Pair,
+}
+
+pub fn kind(bet_id: u16) -> Option<Kind> {
+    match bet_id {
+        1..=4 => Some(Kind::Simple),
+        11..=16 => Some(Kind::Single),
+        21..=26 => Some(Kind::Double),
+        31..=36 => Some(Kind::Triple),
+        37 => Some(Kind::AnyTriple),
+        104..=117 => Some(Kind::Total),
+        212..=256 => Some(Kind::Pair),
+        _ => None,
+    }
+}
+
+// Cell of the standard layout. Row 0 holds from left small, odd, the doubles
+// 1-3, the triples 1-3, any triple, the triples 4-6, the doubles 4-6, even
+// and big, row 1 the totals 4 to 17, row 2 the pairs and row 3 the singles.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BoardPosition {
+    pub row: u8,
+    pub col: u8,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatalogueEntry {
+    pub id: u16,
+    pub kind: Kind,
+    pub name: String,
+    // the dice faces of the bet, or the totals for simple and total bets
+    pub numbers: Vec<u8>,
+    pub ratio: f64,
+    pub position: BoardPosition,
+}
+
+const PAIRS: [u16; 15] = [212, 213, 214, 215, 216, 223, 224, 225, 226, 234, 235, 236, 245, 246, 256];
+
+pub fn entry(id: u16, ratio: f64) -> Option<CatalogueEntry> {
+    let kind = kind(id)?;
+    let face = (id % 10) as u8;
+    let (name, numbers, row, col) = match kind {
+        Kind::Simple => {
+            let (name, totals, col): (&str, Vec<u8>, u8) = match id {
+                1 => ("Big", (11..18).collect(), 16),
+                2 => ("Small", (4..11).collect(), 0),
+                3 => ("Odd", (5..18).step_by(2).collect(), 1),
+                _ => ("Even", (4..17).step_by(2).collect(), 15),
+            };
+            (name.to_string(), totals, 0, col)
+        }
+        Kind::Single => (format!("Single {}", face), vec![face], 3, face - 1),
+        Kind::Double => (
+            format!("Double {}", face),
+            vec![face, face],
+            0,
+            if face <= 3 { face + 1 } else { face + 8 },
+        ),
+        Kind::Triple => (
+            format!("Triple {}", face),
+            vec![face, face, face],
+            0,
+            if face <= 3 { face + 4 } else { face + 5 },
+        ),
+        Kind::AnyTriple => ("Any Triple".to_string(), vec![], 0, 8),
+        Kind::Total => {
+            let total = (id - 100) as u8;
+            (format!("Total {}", total), vec![total], 1, total - 4)
+        }
+        Kind::Pair => {
+            let col = PAIRS.iter().position(|&p| p == id)? as u8;
+            let (a, b) = (((id / 10) % 10) as u8, face);
+            (format!("Pair {}-{}", a, b), vec![a, b], 2, col)
+        }
+    };
+    Some(CatalogueEntry {
+        id,
+        kind,
+        name,
+        numbers,
+        ratio,
+        position: BoardPosition { row, col },
+    })
+}
@@ -0,0 +1,112 @@
+use super::{add, count, payout_map, BetKind, Result};
+use games::dice::{BetId, Ratio};
+use std::collections::HashMap;
+
+// Chuck-a-Luck only has the single number bets, 1:1, 2:1 and 3:1 for one, two
+// or three dice, some houses pay 10:1 on the triple.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Paytable {
+    Standard,
+    TripleTen,
+}
+
+struct Number(u16, u8, f64);
+
+impl BetId for Number {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Ratio for Number {
+    fn ratio(&self) -> f64 {
+        1.0
+    }
+}
+
+impl BetKind for Number {
+    fn bingo(&self, r: &Result) -> u8 {
+        count(r.d1 == self.1) + count(r.d2 == self.1) + count(r.d3 == self.1)
+    }
+
+    fn odds(&self, r: &Result) -> f64 {
+        match self.bingo(r) {
+            3 => self.2,
+            c => self.ratio() * f64::from(c),
+        }
+    }
+}
+
+pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>, paytable: Paytable) {
+    let triple = match paytable {
+        Paytable::Standard => 3.0,
+        Paytable::TripleTen => 10.0,
+    };
+    for face in 1..7 {
+        add(Box::new(Number(1000 + u16::from(face), face, triple)), map);
+    }
+}
+
+pub struct ChuckALuck {
+    paytable: Paytable,
+    all_bets: HashMap<u16, Box<BetKind>>,
+}
+
+impl ChuckALuck {
+    pub fn new(paytable: Paytable) -> ChuckALuck {
+        let mut map = HashMap::<u16, Box<BetKind>>::new();
+        all_bets(&mut map, paytable);
+        ChuckALuck {
+            paytable,
+            all_bets: map,
+        }
+    }
+
+    pub fn paytable(&self) -> Paytable {
+        self.paytable
+    }
+
+    pub fn valid_bets(&self, bet_id: u16) -> bool {
+        self.all_bets.contains_key(&bet_id)
+    }
+
+    pub fn payout_map(&self, d1: u8, d2: u8, d3: u8) -> HashMap<u16, f64> {
+        payout_map(&self.all_bets, d1, d2, d3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payout_map() {
+        let g = ChuckALuck::new(Paytable::Standard);
+        assert_eq!(g.payout_map(1, 2, 3), hashmap!{1001=>2.0, 1002=>2.0, 1003=>2.0});
+        assert_eq!(g.payout_map(5, 2, 5), hashmap!{1005=>3.0, 1002=>2.0});
+        assert_eq!(g.payout_map(6, 6, 6), hashmap!{1006=>4.0});
+        let g = ChuckALuck::new(Paytable::TripleTen);
+        assert_eq!(g.payout_map(5, 2, 5), hashmap!{1005=>3.0, 1002=>2.0});
+        assert_eq!(g.payout_map(6, 6, 6), hashmap!{1006=>11.0});
+        assert!(g.valid_bets(1001));
+        assert!(!g.valid_bets(11));
+    }
+
+    #[test]
+    fn test_rtp() {
+        let rtp = |g: &ChuckALuck| {
+            let mut total = 0.0;
+            for d1 in 1..7 {
+                for d2 in 1..7 {
+                    for d3 in 1..7 {
+                        total += g.payout_map(d1, d2, d3).get(&1001).cloned().unwrap_or(0.0);
+                    }
+                }
+            }
+            total / 216.0
+        };
+        // 199/216 and 206/216
+        assert!((rtp(&ChuckALuck::new(Paytable::Standard)) - 199.0 / 216.0).abs() < 1e-12);
+        assert!((rtp(&ChuckALuck::new(Paytable::TripleTen)) - 206.0 / 216.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,148 @@
+use super::{add, count, payout_map, BetKind, Result};
+use games::dice::{BetId, Ratio};
+use std::collections::HashMap;
+
+// Bầu cua / Fish-Prawn-Crab dice carry a symbol on each face instead of pips.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Symbol {
+    Fish,
+    Prawn,
+    Crab,
+    Rooster,
+    Gourd,
+    Deer,
+}
+
+pub static ALL_SYMBOLS: [Symbol; 6] = [
+    Symbol::Fish,
+    Symbol::Prawn,
+    Symbol::Crab,
+    Symbol::Rooster,
+    Symbol::Gourd,
+    Symbol::Deer,
+];
+
+impl Symbol {
+    // the face of the die, 1 to 6
+    pub fn face(&self) -> u8 {
+        ALL_SYMBOLS.iter().position(|s| s == self).unwrap() as u8 + 1
+    }
+
+    pub fn from_face(face: u8) -> Option<Symbol> {
+        ALL_SYMBOLS.get((face as usize).wrapping_sub(1)).cloned()
+    }
+
+    pub fn bet_id(&self) -> u16 {
+        1200 + u16::from(self.face())
+    }
+}
+
+// 1:1, 2:1 and 3:1 for one, two or three dice showing the symbol
+struct Single(u16, u8);
+
+impl BetId for Single {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Ratio for Single {
+    fn ratio(&self) -> f64 {
+        1.0
+    }
+}
+
+impl BetKind for Single {
+    fn bingo(&self, r: &Result) -> u8 {
+        count(r.d1 == self.1) + count(r.d2 == self.1) + count(r.d3 == self.1)
+    }
+}
+
+// two different symbols on the dice, 5:1
+struct Pair(u16, u8, u8);
+
+impl BetId for Pair {
+    fn id(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Ratio for Pair {
+    fn ratio(&self) -> f64 {
+        5.0
+    }
+}
+
+impl BetKind for Pair {
+    fn bingo(&self, r: &Result) -> u8 {
+        let has = |f| r.d1 == f || r.d2 == f || r.d3 == f;
+        count(has(self.1) && has(self.2))
+    }
+}
+
+pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    for s in &ALL_SYMBOLS {
+        add(Box::new(Single(s.bet_id(), s.face())), map);
+    }
+    for a in 1..7 {
+        for b in (a + 1)..7 {
+            add(Box::new(Pair(1200 + u16::from(a) * 10 + u16::from(b), a, b)), map);
+        }
+    }
+}
+
+pub struct FishPrawnCrab {
+    all_bets: HashMap<u16, Box<BetKind>>,
+}
+
+impl Default for FishPrawnCrab {
+    fn default() -> FishPrawnCrab {
+        FishPrawnCrab::new()
+    }
+}
+
+impl FishPrawnCrab {
+    pub fn new() -> FishPrawnCrab {
+        let mut map = HashMap::<u16, Box<BetKind>>::new();
+        all_bets(&mut map);
+        FishPrawnCrab { all_bets: map }
+    }
+
+    pub fn valid_bets(&self, bet_id: u16) -> bool {
+        self.all_bets.contains_key(&bet_id)
+    }
+
+    pub fn payout_map(&self, s1: Symbol, s2: Symbol, s3: Symbol) -> HashMap<u16, f64> {
+        payout_map(&self.all_bets, s1.face(), s2.face(), s3.face())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol() {
+        assert_eq!(Symbol::Fish.face(), 1);
+        assert_eq!(Symbol::Deer.bet_id(), 1206);
+        assert_eq!(Symbol::from_face(3), Some(Symbol::Crab));
+        assert_eq!(Symbol::from_face(0), None);
+        assert_eq!(Symbol::from_face(7), None);
+    }
+
+    #[test]
+    fn test_payout_map() {
+        let g = FishPrawnCrab::new();
+        assert_eq!(
+            g.payout_map(Symbol::Fish, Symbol::Prawn, Symbol::Fish),
+            hashmap!{1201=>3.0, 1202=>2.0, 1212=>6.0}
+        );
+        assert_eq!(g.payout_map(Symbol::Gourd, Symbol::Gourd, Symbol::Gourd), hashmap!{1205=>4.0});
+        assert_eq!(
+            g.payout_map(Symbol::Crab, Symbol::Rooster, Symbol::Deer),
+            hashmap!{1203=>2.0, 1204=>2.0, 1206=>2.0, 1234=>6.0, 1236=>6.0, 1246=>6.0}
+        );
+        assert!(g.valid_bets(1256));
+        assert!(!g.valid_bets(1200));
+    }
+}
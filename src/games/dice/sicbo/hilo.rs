@@ -0,0 +1,104 @@
+use super::{add, count, payout_map, BetKind, Result};
+use games::dice::{BetId, Ratio};
+use std::collections::HashMap;
+
+// Hi-Lo dice: hi is 11 to 18 and lo 3 to 10, both lose on any triple; hi-lo
+// (eleven) is the total 11 alone.
+enum HiLo {
+    Hi,
+    Lo,
+    Eleven,
+}
+
+impl BetId for HiLo {
+    fn id(&self) -> u16 {
+        match *self {
+            HiLo::Hi => 1101,
+            HiLo::Lo => 1102,
+            HiLo::Eleven => 1103,
+        }
+    }
+}
+
+impl Ratio for HiLo {
+    fn ratio(&self) -> f64 {
+        match *self {
+            HiLo::Hi | HiLo::Lo => 1.0,
+            HiLo::Eleven => 6.0,
+        }
+    }
+}
+
+impl BetKind for HiLo {
+    fn bingo(&self, r: &Result) -> u8 {
+        match *self {
+            HiLo::Hi => count(!r.is_triple && r.sum >= 11),
+            HiLo::Lo => count(!r.is_triple && r.sum <= 10),
+            HiLo::Eleven => count(r.sum == 11),
+        }
+    }
+}
+
+pub fn all_bets(map: &mut HashMap<u16, Box<BetKind>>) {
+    add(Box::new(HiLo::Hi), map);
+    add(Box::new(HiLo::Lo), map);
+    add(Box::new(HiLo::Eleven), map);
+}
+
+pub struct HiLoDice {
+    all_bets: HashMap<u16, Box<BetKind>>,
+}
+
+impl Default for HiLoDice {
+    fn default() -> HiLoDice {
+        HiLoDice::new()
+    }
+}
+
+impl HiLoDice {
+    pub fn new() -> HiLoDice {
+        let mut map = HashMap::<u16, Box<BetKind>>::new();
+        all_bets(&mut map);
+        HiLoDice { all_bets: map }
+    }
+
+    pub fn valid_bets(&self, bet_id: u16) -> bool {
+        self.all_bets.contains_key(&bet_id)
+    }
+
+    pub fn payout_map(&self, d1: u8, d2: u8, d3: u8) -> HashMap<u16, f64> {
+        payout_map(&self.all_bets, d1, d2, d3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payout_map() {
+        let g = HiLoDice::new();
+        assert_eq!(g.payout_map(1, 2, 3), hashmap!{1102=>2.0});
+        assert_eq!(g.payout_map(6, 4, 1), hashmap!{1101=>2.0, 1103=>7.0});
+        assert_eq!(g.payout_map(6, 6, 6), hashmap!{});
+        assert_eq!(g.payout_map(1, 1, 1), hashmap!{});
+    }
+
+    #[test]
+    fn test_rtp() {
+        let g = HiLoDice::new();
+        let mut total = HashMap::<u16, f64>::new();
+        for d1 in 1..7 {
+            for d2 in 1..7 {
+                for d3 in 1..7 {
+                    for (id, v) in g.payout_map(d1, d2, d3) {
+                        *total.entry(id).or_insert(0.0) += v / 216.0;
+                    }
+                }
+            }
+        }
+        assert!((total[&1101] - 210.0 / 216.0).abs() < 1e-12);
+        assert!((total[&1102] - 210.0 / 216.0).abs() < 1e-12);
+        assert!((total[&1103] - 189.0 / 216.0).abs() < 1e-12);
+    }
+}
@@ -4,6 +4,11 @@ pub mod triple;
 pub mod total;
 pub mod pair;
 pub mod simple;
+pub mod catalogue;
+pub mod chuckaluck;
+pub mod hilo;
+pub mod fishprawncrab;
+pub mod boost;
 pub mod all;
 
 use std::collections::HashMap;
@@ -39,8 +44,24 @@ fn count(b: bool) -> u8 {
 
 pub trait BetKind: BetId + Ratio {
     fn bingo(&self, r: &Result) -> u8;
+
+    // winnings per unit staked, paid once per hit unless the paytable says otherwise
+    fn odds(&self, r: &Result) -> f64 {
+        self.ratio() * f64::from(self.bingo(r))
+    }
 }
 
 fn add(b: Box<BetKind>, map: &mut HashMap<u16, Box<BetKind>>) {
     map.insert(b.id(), b);
 }
+
+fn payout_map(bets: &HashMap<u16, Box<BetKind>>, d1: u8, d2: u8, d3: u8) -> HashMap<u16, f64> {
+    let r = Result::new(d1, d2, d3);
+    let mut map = HashMap::<u16, f64>::new();
+    for (&id, b) in bets {
+        if b.bingo(&r) > 0 {
+            map.insert(id, b.odds(&r) + 1.0);
+        }
+    }
+    map
+}
@@ -1,5 +1,6 @@
 pub mod card;
 pub mod dice;
+pub mod rng;
 
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -9,13 +10,36 @@ pub trait BetSerde: Sized {
     fn to_u16(&self) -> u16;
 }
 
+// games keyed by bet id directly, like roulette and sic bo.
+impl BetSerde for u16 {
+    fn from_u16(id: u16) -> Option<u16> {
+        Some(id)
+    }
+
+    fn to_u16(&self) -> u16 {
+        *self
+    }
+}
+
 pub trait Game {
     type B: BetSerde + Eq + Hash;
-    
+
+    fn name(&self) -> &'static str;
+
+    // the bets a hand is won on, the others are side bets
+    fn main_bets(&self) -> &[Self::B] {
+        &[]
+    }
+
+    // games keyed by u16 refuse the ids they don't offer here
+    fn accepts_bet(&self, _bet: &Self::B) -> bool {
+        true
+    }
+
     fn from_raw_bets(&self, bets: &HashMap<u16, f64>) -> Option<HashMap<Self::B, f64>> {
         let mut m = HashMap::<Self::B, f64>::new();
         for (&k, &v) in bets {
-            if let Some(b) = Self::B::from_u16(k) {
+            if let Some(b) = Self::B::from_u16(k).filter(|b| self.accepts_bet(b)) {
                 m.insert(b, v);
             } else {
                 return None;
@@ -24,3 +48,7 @@ pub trait Game {
         Some(m)
     }
 }
+
+pub fn raw_payouts<B: BetSerde + Eq + Hash>(payouts: &HashMap<B, f64>) -> HashMap<u16, f64> {
+    payouts.iter().map(|(b, &v)| (b.to_u16(), v)).collect()
+}
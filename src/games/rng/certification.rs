@@ -0,0 +1,246 @@
+use super::{roll_dice, spin, RandomSource};
+
+// Offline statistical checks of a RandomSource, run on draws mapped to sic bo
+// dice and roulette numbers, in the shape a test lab expects for submission.
+
+// significance level of every test
+pub const ALPHA: f64 = 0.01;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    pub name: String,
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+impl TestResult {
+    pub fn passed(&self) -> bool {
+        self.p_value >= ALPHA
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificationReport {
+    pub samples: usize,
+    pub results: Vec<TestResult>,
+}
+
+impl CertificationReport {
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|r| r.passed())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut s = format!("samples: {}\nalpha: {}\n", self.samples, ALPHA);
+        s.push_str("test,statistic,p_value,result\n");
+        for r in &self.results {
+            s.push_str(&format!(
+                "{},{:.4},{:.4},{}\n",
+                r.name,
+                r.statistic,
+                r.p_value,
+                if r.passed() { "PASS" } else { "FAIL" }
+            ));
+        }
+        s.push_str(&format!(
+            "overall: {}\n",
+            if self.passed() { "PASS" } else { "FAIL" }
+        ));
+        s
+    }
+}
+
+pub fn run_suite<R: RandomSource>(src: &mut R, samples: usize) -> CertificationReport {
+    // nothing drawn is nothing certified
+    if samples == 0 {
+        return CertificationReport {
+            samples,
+            results: vec![TestResult {
+                name: "sample size".to_string(),
+                statistic: 0.0,
+                p_value: 0.0,
+            }],
+        };
+    }
+    let balls: Vec<f64> = (0..samples).map(|_| f64::from(spin(src, 37))).collect();
+    let mut faces = Vec::with_capacity(samples * 3);
+    let mut totals = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let (d1, d2, d3) = roll_dice(src);
+        faces.push(f64::from(d1));
+        faces.push(f64::from(d2));
+        faces.push(f64::from(d3));
+        totals.push(f64::from(d1 + d2 + d3));
+    }
+    let results = vec![
+        chi_square("roulette chi-square", &balls, &uniform(37), 0.0),
+        runs("roulette runs", &balls),
+        serial_correlation("roulette serial correlation", &balls),
+        chi_square("dice face chi-square", &faces, &uniform(6), 1.0),
+        chi_square("dice total chi-square", &totals, &total_probabilities(), 3.0),
+        runs("dice runs", &faces),
+        serial_correlation("dice serial correlation", &faces),
+    ];
+    CertificationReport { samples, results }
+}
+
+fn uniform(n: usize) -> Vec<f64> {
+    vec![1.0 / n as f64; n]
+}
+
+// probabilities of three dice totals 3..18
+fn total_probabilities() -> Vec<f64> {
+    let mut p = vec![0.0; 16];
+    for d1 in 1..7 {
+        for d2 in 1..7 {
+            for d3 in 1..7 {
+                p[d1 + d2 + d3 - 3] += 1.0 / 216.0;
+            }
+        }
+    }
+    p
+}
+
+// values are categories counted from `first`
+pub fn chi_square(name: &str, values: &[f64], probabilities: &[f64], first: f64) -> TestResult {
+    let mut observed = vec![0.0; probabilities.len()];
+    for v in values {
+        observed[(v - first) as usize] += 1.0;
+    }
+    let n = values.len() as f64;
+    let statistic = observed
+        .iter()
+        .zip(probabilities)
+        .fold(0.0, |a, (o, p)| a + (o - n * p).powi(2) / (n * p));
+    let k = (probabilities.len() - 1) as f64;
+    TestResult {
+        name: name.to_string(),
+        statistic,
+        p_value: chi_square_upper(statistic, k),
+    }
+}
+
+// Wald-Wolfowitz runs above and below the median
+pub fn runs(name: &str, values: &[f64]) -> TestResult {
+    let m = median(values);
+    let signs: Vec<bool> = values.iter().filter(|&&v| v != m).map(|&v| v > m).collect();
+    let n1 = signs.iter().filter(|&&s| s).count() as f64;
+    let n2 = signs.len() as f64 - n1;
+    let r = 1.0 + signs.windows(2).filter(|w| w[0] != w[1]).count() as f64;
+    let n = n1 + n2;
+    let mean = 2.0 * n1 * n2 / n + 1.0;
+    let var = 2.0 * n1 * n2 * (2.0 * n1 * n2 - n) / (n * n * (n - 1.0));
+    let z = (r - mean) / var.sqrt();
+    TestResult {
+        name: name.to_string(),
+        statistic: z,
+        p_value: two_sided(z),
+    }
+}
+
+// lag 1 autocorrelation, sqrt(n) * r is standard normal for independent draws
+pub fn serial_correlation(name: &str, values: &[f64]) -> TestResult {
+    let n = values.len() as f64;
+    let m = values.iter().fold(0.0, |a, v| a + v) / n;
+    let num = values
+        .windows(2)
+        .fold(0.0, |a, w| a + (w[0] - m) * (w[1] - m));
+    let den = values.iter().fold(0.0, |a, v| a + (v - m).powi(2));
+    let r = num / den;
+    TestResult {
+        name: name.to_string(),
+        statistic: r,
+        p_value: two_sided(r * n.sqrt()),
+    }
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut v = values.to_vec();
+    v.sort_by(|a, b| a.total_cmp(b));
+    let l = v.len();
+    (v[(l - 1) / 2] + v[l / 2]) / 2.0
+}
+
+fn two_sided(z: f64) -> f64 {
+    2.0 * (1.0 - normal_cdf(z.abs()))
+}
+
+// Wilson-Hilferty approximation of the chi-square upper tail
+fn chi_square_upper(x: f64, k: f64) -> f64 {
+    let s = 2.0 / (9.0 * k);
+    let z = ((x / k).powf(1.0 / 3.0) - (1.0 - s)) / s.sqrt();
+    1.0 - normal_cdf(z)
+}
+
+fn normal_cdf(z: f64) -> f64 {
+    0.5 * (1.0 + erf(z / 2f64.sqrt()))
+}
+
+// Abramowitz and Stegun 7.1.26
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let y = 1.0
+        - (((((1.061_405_429 * t - 1.453_152_027) * t) + 1.421_413_741) * t - 0.284_496_736) * t
+            + 0.254_829_592) * t * (-x * x).exp();
+    sign * y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::rng::fair::ProvablyFair;
+
+    struct Counter(u32);
+
+    impl RandomSource for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.0 = self.0.wrapping_add(1);
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-6);
+        assert!((normal_cdf(1.96) - 0.975).abs() < 1e-3);
+        assert!((chi_square_upper(21.666, 9.0) - 0.01).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_total_probabilities() {
+        let p = total_probabilities();
+        assert_eq!(p.len(), 16);
+        assert!((p.iter().fold(0.0, |a, v| a + v) - 1.0).abs() < 1e-9);
+        assert!((p[10 - 3] - 27.0 / 216.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_suite_passes() {
+        let r = run_suite(&mut ProvablyFair::new("server", "client", 1), 20000);
+        assert_eq!(r.results.len(), 7);
+        assert!(r.passed(), "{}", r.to_text());
+    }
+
+    #[test]
+    fn test_suite_fails() {
+        let r = run_suite(&mut Counter(0), 20000);
+        assert!(!r.passed());
+        assert!(r.to_text().ends_with("overall: FAIL\n"));
+    }
+
+    #[test]
+    fn test_no_samples() {
+        let r = run_suite(&mut Counter(0), 0);
+        assert_eq!(r.results.len(), 1);
+        assert!(!r.passed());
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(2.5, median(&[4.0, 1.0, 3.0, 2.0]));
+        // NaN sorts last instead of panicking
+        assert_eq!(4.0, median(&[3.0, ::std::f64::NAN, 1.0, 5.0, 4.0]));
+    }
+}
@@ -0,0 +1,157 @@
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use games::card::Card;
+use super::{roll_dice, shuffled_shoe, spin, RandomSource};
+
+type HmacSha256 = Hmac<Sha256>;
+
+// Provably fair stream: HMAC-SHA256(server_seed, "client_seed:nonce:block") gives
+// 32 bytes per block, read as big endian u32. The hash of the server seed is
+// published before the round and the seed itself is revealed afterwards.
+pub struct ProvablyFair {
+    server_seed: String,
+    client_seed: String,
+    nonce: u64,
+    block: u64,
+    buffer: Vec<u8>,
+    pos: usize,
+}
+
+impl ProvablyFair {
+    pub fn new(server_seed: &str, client_seed: &str, nonce: u64) -> ProvablyFair {
+        ProvablyFair {
+            server_seed: server_seed.to_string(),
+            client_seed: client_seed.to_string(),
+            nonce,
+            block: 0,
+            buffer: vec![],
+            pos: 0,
+        }
+    }
+
+    pub fn commitment(&self) -> String {
+        commitment(&self.server_seed)
+    }
+
+    fn refill(&mut self) {
+        let mut mac = <HmacSha256 as Mac>::new_from_slice(self.server_seed.as_bytes())
+            .expect("hmac takes keys of any size");
+        let msg = format!("{}:{}:{}", self.client_seed, self.nonce, self.block);
+        mac.update(msg.as_bytes());
+        self.buffer = mac.finalize().into_bytes().to_vec();
+        self.pos = 0;
+        self.block += 1;
+    }
+}
+
+impl RandomSource for ProvablyFair {
+    fn next_u32(&mut self) -> u32 {
+        if self.pos + 4 > self.buffer.len() {
+            self.refill();
+        }
+        let b = &self.buffer[self.pos..self.pos + 4];
+        self.pos += 4;
+        (u32::from(b[0]) << 24) | (u32::from(b[1]) << 16) | (u32::from(b[2]) << 8) | u32::from(b[3])
+    }
+}
+
+// hex encoded sha256 of the server seed
+pub fn commitment(server_seed: &str) -> String {
+    to_hex(&Sha256::digest(server_seed.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// replays the round if the revealed server seed matches the published commitment.
+pub fn verify(commitment_hash: &str, server_seed: &str, client_seed: &str, nonce: u64) -> Option<ProvablyFair> {
+    if commitment(server_seed) == commitment_hash.to_lowercase() {
+        Some(ProvablyFair::new(server_seed, client_seed, nonce))
+    } else {
+        None
+    }
+}
+
+pub fn verify_roulette(
+    commitment_hash: &str,
+    server_seed: &str,
+    client_seed: &str,
+    nonce: u64,
+    pockets: u8,
+    ball: u8,
+) -> bool {
+    verify(commitment_hash, server_seed, client_seed, nonce)
+        .is_some_and(|mut pf| spin(&mut pf, pockets) == ball)
+}
+
+pub fn verify_dice(
+    commitment_hash: &str,
+    server_seed: &str,
+    client_seed: &str,
+    nonce: u64,
+    dice: (u8, u8, u8),
+) -> bool {
+    verify(commitment_hash, server_seed, client_seed, nonce)
+        .is_some_and(|mut pf| roll_dice(&mut pf) == dice)
+}
+
+pub fn verify_shoe(
+    commitment_hash: &str,
+    server_seed: &str,
+    client_seed: &str,
+    nonce: u64,
+    cards: &[Card],
+) -> bool {
+    // only whole decks can be rebuilt, an empty shoe proves nothing
+    if cards.is_empty() || cards.len() % 52 != 0 {
+        return false;
+    }
+    verify(commitment_hash, server_seed, client_seed, nonce)
+        .is_some_and(|mut pf| shuffled_shoe(cards.len() / 52, &mut pf)[..] == *cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment() {
+        assert_eq!(
+            commitment("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let mut a = ProvablyFair::new("server", "client", 1);
+        let mut b = ProvablyFair::new("server", "client", 1);
+        let mut c = ProvablyFair::new("server", "client", 2);
+        let va: Vec<u32> = (0..20).map(|_| a.next_u32()).collect();
+        let vb: Vec<u32> = (0..20).map(|_| b.next_u32()).collect();
+        let vc: Vec<u32> = (0..20).map(|_| c.next_u32()).collect();
+        assert_eq!(va, vb);
+        assert_ne!(va, vc);
+    }
+
+    #[test]
+    fn test_verify() {
+        let hash = commitment("server");
+        let ball = spin(&mut ProvablyFair::new("server", "client", 7), 37);
+        assert!(verify_roulette(&hash, "server", "client", 7, 37, ball));
+        assert!(!verify_roulette(&hash, "server", "client", 7, 37, (ball + 1) % 37));
+        assert!(!verify_roulette(&hash, "other", "client", 7, 37, ball));
+
+        let dice = roll_dice(&mut ProvablyFair::new("server", "client", 8));
+        assert!(verify_dice(&hash, "server", "client", 8, dice));
+        assert!(!verify_dice(&hash, "other", "client", 8, dice));
+
+        let shoe = shuffled_shoe(8, &mut ProvablyFair::new("server", "client", 9));
+        assert!(verify_shoe(&hash, "server", "client", 9, &shoe));
+        assert!(!verify_shoe(&hash, "server", "client", 9, &shoe[1..]));
+        assert!(!verify_shoe(&hash, "server", "client", 9, &[]));
+        assert!(!verify_shoe(&hash, "server", "client", 9, &shoe[..51]));
+        assert!(!verify_shoe(&hash, "server", "client", 9, &shoe[..104]));
+    }
+}
@@ -0,0 +1,142 @@
+pub mod fair;
+pub mod certification;
+
+use rand::{Rng, StdRng, ThreadRng};
+use games::card::{new_shoe, Card};
+
+// source of randomness for every draw, either `rand` or the provably fair scheme.
+pub trait RandomSource {
+    fn next_u32(&mut self) -> u32;
+
+    // uniform in [0, n), values above the last full multiple of n are rejected to avoid modulo bias.
+    fn below(&mut self, n: u32) -> u32 {
+        assert!(n > 0);
+        let limit = u32::MAX - u32::MAX % n;
+        loop {
+            let v = self.next_u32();
+            if v < limit {
+                return v % n;
+            }
+        }
+    }
+}
+
+// lets tables hold the source chosen at runtime as a boxed trait object.
+impl<R: RandomSource + ?Sized> RandomSource for Box<R> {
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+}
+
+impl RandomSource for ThreadRng {
+    fn next_u32(&mut self) -> u32 {
+        Rng::next_u32(self)
+    }
+}
+
+impl RandomSource for StdRng {
+    fn next_u32(&mut self) -> u32 {
+        Rng::next_u32(self)
+    }
+}
+
+// pockets is 37 for a single zero wheel.
+pub fn spin<R: RandomSource>(src: &mut R, pockets: u8) -> u8 {
+    src.below(u32::from(pockets)) as u8
+}
+
+pub fn roll_dice<R: RandomSource>(src: &mut R) -> (u8, u8, u8) {
+    (roll(src), roll(src), roll(src))
+}
+
+pub fn roll<R: RandomSource>(src: &mut R) -> u8 {
+    src.below(6) as u8 + 1
+}
+
+// Fisher-Yates
+pub fn shuffle<T, R: RandomSource>(items: &mut [T], src: &mut R) {
+    for i in (1..items.len()).rev() {
+        let j = src.below(i as u32 + 1) as usize;
+        items.swap(i, j);
+    }
+}
+
+// picks one of the values with chance proportional to its weight
+pub fn weighted<T: Copy, R: RandomSource>(items: &[(T, u32)], src: &mut R) -> T {
+    let total = items.iter().fold(0, |a, &(_, w)| a + w);
+    let mut r = src.below(total);
+    for &(v, w) in items {
+        if r < w {
+            return v;
+        }
+        r -= w;
+    }
+    unreachable!()
+}
+
+pub fn shuffled_shoe<R: RandomSource>(decks: usize, src: &mut R) -> Vec<Card> {
+    let mut cards = new_shoe(decks);
+    shuffle(&mut cards, src);
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::thread_rng;
+
+    struct Counter(u32);
+
+    impl RandomSource for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.0 = self.0.wrapping_add(1);
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_below() {
+        let mut c = Counter(0);
+        assert_eq!(c.below(6), 1);
+        assert_eq!(c.below(6), 2);
+        let mut c = Counter(u32::MAX - 2);
+        assert_eq!(c.below(6), 0);
+    }
+
+    #[test]
+    fn test_draws() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            assert!(spin(&mut rng, 37) <= 36);
+            let (d1, d2, d3) = roll_dice(&mut rng);
+            assert!(d1 >= 1 && d1 <= 6 && d2 >= 1 && d2 <= 6 && d3 >= 1 && d3 <= 6);
+        }
+    }
+
+    #[test]
+    fn test_weighted() {
+        let mut c = Counter(0);
+        let items = [('a', 1), ('b', 0), ('c', 2)];
+        assert_eq!(weighted(&items, &mut c), 'c');
+        assert_eq!(weighted(&items, &mut c), 'c');
+        assert_eq!(weighted(&items, &mut c), 'a');
+    }
+
+    #[test]
+    fn test_boxed_source() {
+        let mut src: Box<RandomSource> = Box::new(Counter(0));
+        assert_eq!(spin(&mut src, 37), 1);
+        assert_eq!(roll_dice(&mut src), (3, 4, 5));
+    }
+
+    #[test]
+    fn test_shuffled_shoe() {
+        let mut cards = shuffled_shoe(8, &mut thread_rng());
+        assert_eq!(cards.len(), 416);
+        let mut sorted = new_shoe(8);
+        let key = |c: &Card| (c.suit as u8, c.rank as u8);
+        cards.sort_by_key(&key);
+        sorted.sort_by_key(&key);
+        assert_eq!(cards, sorted);
+    }
+}
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use tables::limits::LimitProfile;
+
 // map bets into u16 before send to other system.
 /*
 trait BetActionDeps {
@@ -6,4 +9,67 @@ trait BetActionDeps {
     fn pay_bets(user_id:&str, round_id:u64, amount:f64);
     fn notify_bets(user_id:&str, round_id:u64, bets:HashMap<u16, f64>);
 }
-*/
\ No newline at end of file
+*/
+
+pub trait LimitSource {
+    fn load_player_limits(&self, user_id: &str) -> Vec<LimitProfile>;
+}
+
+pub struct LimitCache {
+    source: Box<LimitSource>,
+    cache: HashMap<String, Vec<LimitProfile>>,
+}
+
+impl LimitCache {
+    pub fn new(source: Box<LimitSource>) -> LimitCache {
+        LimitCache {
+            source,
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn profile(&mut self, user_id: &str, name: &str) -> Option<&LimitProfile> {
+        if !self.cache.contains_key(user_id) {
+            let profiles = self.source.load_player_limits(user_id);
+            self.cache.insert(user_id.to_string(), profiles);
+        }
+        self.cache[user_id].iter().find(|p| p.name == name)
+    }
+
+    pub fn invalidate(&mut self, user_id: &str) {
+        self.cache.remove(user_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::Cell;
+    use tables::common::Limit;
+
+    struct Source(Rc<Cell<u32>>);
+
+    impl LimitSource for Source {
+        fn load_player_limits(&self, _user_id: &str) -> Vec<LimitProfile> {
+            self.0.set(self.0.get() + 1);
+            vec![LimitProfile::new("vip", Limit::new(100.0, 10000.0), 50000.0)]
+        }
+    }
+
+    #[test]
+    fn test_limit_cache() {
+        let loads = Rc::new(Cell::new(0));
+        let mut c = LimitCache::new(Box::new(Source(loads.clone())));
+        assert!(c.profile("u1", "vip").is_some());
+        assert!(c.profile("u1", "regular").is_none());
+        assert_eq!(loads.get(), 1);
+        c.invalidate("u1");
+        assert!(c.profile("u1", "vip").is_some());
+        assert_eq!(loads.get(), 2);
+    }
+}
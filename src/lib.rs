@@ -1,6 +1,8 @@
 #[macro_use]
 extern crate maplit;
 extern crate rand;
+extern crate sha2;
+extern crate hmac;
 
 #[macro_use]
 extern crate lazy_static;
@@ -8,4 +10,5 @@ extern crate lazy_static;
 pub mod games;
 pub mod web;
 pub mod tables;
-pub mod integration;
\ No newline at end of file
+pub mod integration;
+pub mod reporting;
\ No newline at end of file
@@ -0,0 +1,310 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettlementRecord {
+    pub table_id: u16,
+    pub game: &'static str,
+    pub round_id: u64,
+    pub hour: u64,
+    pub user_id: String,
+    pub bet_id: u16,
+    pub stake: f64,
+    pub payout: f64,
+    pub offset: f64,
+}
+
+impl SettlementRecord {
+    pub fn is_push(&self) -> bool {
+        self.payout == self.stake
+    }
+
+    // pushes and offset bets are not valid turnover.
+    pub fn valid_turnover(&self) -> f64 {
+        if self.is_push() {
+            0.0
+        } else {
+            self.stake - self.offset
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Totals {
+    pub stake: f64,
+    pub payout: f64,
+    pub valid_turnover: f64,
+    theoretical_stake: f64,
+    theoretical_payout: f64,
+}
+
+impl Totals {
+    fn add(&mut self, r: &SettlementRecord, theoretical_rtp: Option<f64>) {
+        self.stake += r.stake;
+        self.payout += r.payout;
+        self.valid_turnover += r.valid_turnover();
+        if let Some(rtp) = theoretical_rtp {
+            self.theoretical_stake += r.stake;
+            self.theoretical_payout += r.stake * rtp;
+        }
+    }
+
+    fn merge(&mut self, t: &Totals) {
+        self.stake += t.stake;
+        self.payout += t.payout;
+        self.valid_turnover += t.valid_turnover;
+        self.theoretical_stake += t.theoretical_stake;
+        self.theoretical_payout += t.theoretical_payout;
+    }
+
+    // gross gaming revenue
+    pub fn ggr(&self) -> f64 {
+        self.stake - self.payout
+    }
+
+    pub fn rtp(&self) -> Option<f64> {
+        ratio(self.payout, self.stake)
+    }
+
+    pub fn theoretical_rtp(&self) -> Option<f64> {
+        ratio(self.theoretical_payout, self.theoretical_stake)
+    }
+}
+
+fn ratio(a: f64, b: f64) -> Option<f64> {
+    if b > 0.0 {
+        Some(a / b)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    pub table_id: u16,
+    pub game: &'static str,
+    pub bet_id: u16,
+    pub user_id: String,
+    pub hour: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Dimension {
+    Table,
+    Game,
+    BetType,
+    Player,
+    Hour,
+}
+
+impl Key {
+    fn of(r: &SettlementRecord) -> Key {
+        Key {
+            table_id: r.table_id,
+            game: r.game,
+            bet_id: r.bet_id,
+            user_id: r.user_id.clone(),
+            hour: r.hour,
+        }
+    }
+
+    fn value(&self, d: Dimension) -> String {
+        match d {
+            Dimension::Table => self.table_id.to_string(),
+            Dimension::Game => self.game.to_string(),
+            Dimension::BetType => self.bet_id.to_string(),
+            Dimension::Player => self.user_id.clone(),
+            Dimension::Hour => self.hour.to_string(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Report {
+    theoretical_rtp: HashMap<(&'static str, u16), f64>,
+    rows: BTreeMap<Key, Totals>,
+}
+
+impl Report {
+    pub fn new() -> Report {
+        Report::default()
+    }
+
+    pub fn set_theoretical_rtp(&mut self, game: &'static str, bet_id: u16, rtp: f64) {
+        self.theoretical_rtp.insert((game, bet_id), rtp);
+    }
+
+    pub fn add(&mut self, r: &SettlementRecord) {
+        let rtp = self.theoretical_rtp.get(&(r.game, r.bet_id)).cloned();
+        self.rows.entry(Key::of(r)).or_default().add(r, rtp);
+    }
+
+    pub fn add_all(&mut self, records: &[SettlementRecord]) {
+        for r in records {
+            self.add(r);
+        }
+    }
+
+    pub fn rows(&self) -> &BTreeMap<Key, Totals> {
+        &self.rows
+    }
+
+    pub fn total(&self) -> Totals {
+        let mut t = Totals::default();
+        for v in self.rows.values() {
+            t.merge(v);
+        }
+        t
+    }
+
+    pub fn group_by(&self, d: Dimension) -> BTreeMap<String, Totals> {
+        let mut m = BTreeMap::<String, Totals>::new();
+        for (k, v) in &self.rows {
+            m.entry(k.value(d)).or_default().merge(v);
+        }
+        m
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut s = String::from(
+            "table_id,game,bet_id,user_id,hour,stake,payout,ggr,valid_turnover,rtp,theoretical_rtp\n",
+        );
+        for (k, v) in &self.rows {
+            s.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{}\n",
+                k.table_id,
+                csv_field(k.game),
+                k.bet_id,
+                csv_field(&k.user_id),
+                k.hour,
+                v.stake,
+                v.payout,
+                v.ggr(),
+                v.valid_turnover,
+                opt_str(v.rtp(), ""),
+                opt_str(v.theoretical_rtp(), "")
+            ));
+        }
+        s
+    }
+
+    pub fn to_json(&self) -> String {
+        let rows: Vec<String> = self.rows
+            .iter()
+            .map(|(k, v)| {
+                format!(
+                    "{{\"table_id\":{},\"game\":{},\"bet_id\":{},\"user_id\":{},\"hour\":{},\"stake\":{},\"payout\":{},\"ggr\":{},\"valid_turnover\":{},\"rtp\":{},\"theoretical_rtp\":{}}}",
+                    k.table_id,
+                    json_str(k.game),
+                    k.bet_id,
+                    json_str(&k.user_id),
+                    k.hour,
+                    v.stake,
+                    v.payout,
+                    v.ggr(),
+                    v.valid_turnover,
+                    opt_str(v.rtp(), "null"),
+                    opt_str(v.theoretical_rtp(), "null")
+                )
+            })
+            .collect();
+        format!("[{}]", rows.join(","))
+    }
+}
+
+fn opt_str(v: Option<f64>, none: &str) -> String {
+    v.map(|x| x.to_string()).unwrap_or_else(|| none.to_string())
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn json_str(s: &str) -> String {
+    let mut r = String::with_capacity(s.len() + 2);
+    r.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => r.push_str("\\\""),
+            '\\' => r.push_str("\\\\"),
+            '\n' => r.push_str("\\n"),
+            '\r' => r.push_str("\\r"),
+            '\t' => r.push_str("\\t"),
+            c if (c as u32) < 0x20 => r.push_str(&format!("\\u{:04x}", c as u32)),
+            c => r.push(c),
+        }
+    }
+    r.push('"');
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(user_id: &str, bet_id: u16, stake: f64, payout: f64, offset: f64) -> SettlementRecord {
+        SettlementRecord {
+            table_id: 1,
+            game: "commission_baccarat",
+            round_id: 1,
+            hour: 420000,
+            user_id: user_id.to_string(),
+            bet_id,
+            stake,
+            payout,
+            offset,
+        }
+    }
+
+    fn report() -> Report {
+        let mut r = Report::new();
+        r.set_theoretical_rtp("commission_baccarat", 1, 0.9894);
+        r.add_all(&[
+            record("u1", 1, 100.0, 195.0, 0.0),
+            record("u1", 2, 100.0, 0.0, 100.0),
+            record("u2", 1, 50.0, 50.0, 0.0),
+            record("u2", 3, 10.0, 0.0, 0.0),
+        ]);
+        r
+    }
+
+    #[test]
+    fn test_totals() {
+        let t = report().total();
+        assert_eq!(t.stake, 260.0);
+        assert_eq!(t.payout, 245.0);
+        assert_eq!(t.ggr(), 15.0);
+        assert_eq!(t.valid_turnover, 110.0);
+        assert_eq!(t.theoretical_rtp(), Some(0.9894));
+    }
+
+    #[test]
+    fn test_group_by() {
+        let g = report().group_by(Dimension::Player);
+        assert_eq!(g["u1"].stake, 200.0);
+        assert_eq!(g["u1"].ggr(), 5.0);
+        assert_eq!(g["u2"].valid_turnover, 10.0);
+        let g = report().group_by(Dimension::BetType);
+        assert_eq!(g.len(), 3);
+        assert_eq!(g["1"].rtp(), Some(245.0 / 150.0));
+        assert_eq!(g["3"].theoretical_rtp(), None);
+    }
+
+    #[test]
+    fn test_export() {
+        let mut r = Report::new();
+        r.add(&record("a,\"b\"", 3, 10.0, 0.0, 0.0));
+        assert_eq!(
+            r.to_csv(),
+            "table_id,game,bet_id,user_id,hour,stake,payout,ggr,valid_turnover,rtp,theoretical_rtp\n\
+             1,commission_baccarat,3,\"a,\"\"b\"\"\",420000,10,0,10,10,0,\n"
+        );
+        assert_eq!(
+            r.to_json(),
+            "[{\"table_id\":1,\"game\":\"commission_baccarat\",\"bet_id\":3,\"user_id\":\"a,\\\"b\\\"\",\"hour\":420000,\"stake\":10,\"payout\":0,\"ggr\":10,\"valid_turnover\":10,\"rtp\":0,\"theoretical_rtp\":null}]"
+        );
+    }
+}
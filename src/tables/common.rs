@@ -1,10 +1,21 @@
 use std::hash::Hash;
 use std::collections::HashMap;
-use games::Game;
+use std::mem;
+use games::card::baccarat::common::Baccarat;
+use games::card::baccarat::detail::HandDetail;
+use games::card::baccarat::squeeze::{SqueezeDealer, SqueezeError};
+use games::{BetSerde, Game};
+use integration::{LimitCache, LimitSource};
+use reporting::SettlementRecord;
+use super::offset::{OffsetAction, OffsetPolicy};
+use super::timing::{Phase, RoundClock, RoundError, TimingProfile};
 
 pub struct Limit(f64, f64);
 
 impl Limit {
+    pub fn new(min: f64, max: f64) -> Limit {
+        Limit(min, max)
+    }
     pub fn min(&self) -> f64 {
         self.0
     }
@@ -13,21 +24,60 @@ impl Limit {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum BetError {
+    RoundMismatch,
+    InvalidBets,
+    OffsetExceeded(f64),
+    UnknownProfile,
+    LimitChanged,
+    BelowMin(u16),
+    AboveMax(u16),
+    TableMaxExceeded,
+    BettingClosed,
+}
+
+// a round that left betting, timed out ones included, takes no more bets
+impl From<RoundError> for BetError {
+    fn from(_: RoundError) -> BetError {
+        BetError::BettingClosed
+    }
+}
+
 #[derive(Debug)]
 pub struct PlayerBet<T: Eq + Hash> {
     pub uuid: String,
     pub user_id: String,
+    pub profile: String,
     pub bets: HashMap<T, f64>,
+    pub offset: f64,
 }
 
 impl<T: Eq + Hash> PlayerBet<T> {
-    pub fn new(uuid: String, user_id: String, bets: HashMap<T, f64>) -> PlayerBet<T> {
+    pub fn new(
+        uuid: String,
+        user_id: String,
+        profile: String,
+        bets: HashMap<T, f64>,
+        offset: f64,
+    ) -> PlayerBet<T> {
         PlayerBet {
             uuid,
             user_id,
+            profile,
             bets,
+            offset,
         }
     }
+
+    pub fn total(&self) -> f64 {
+        self.bets.values().fold(0.0, |a, v| a + v)
+    }
+
+    // the flagged offset is not counted as turnover.
+    pub fn turnover(&self) -> f64 {
+        self.total() - self.offset
+    }
 }
 
 pub struct Round {
@@ -43,18 +93,469 @@ pub struct Table<G: Game> {
     pub round: Round,
     pub current_bets: Vec<PlayerBet<G::B>>,
     pub previous_bets: Vec<PlayerBet<G::B>>,
+    pub imprisoned_bets: Vec<PlayerBet<G::B>>,
+    pub offset: OffsetPolicy,
+    pub player_limits: LimitCache,
+    pub clock: RoundClock,
+    // hands settled in this shoe, oldest first
+    pub history: Vec<HandDetail>,
 }
 
 impl<G: Game> Table<G> {
+    pub fn new(
+        id: u16,
+        game: G,
+        round: Round,
+        offset: OffsetPolicy,
+        limits: Box<LimitSource>,
+    ) -> Table<G> {
+        Table {
+            id,
+            game,
+            current_bets: vec![],
+            previous_bets: vec![],
+            imprisoned_bets: vec![],
+            offset,
+            clock: RoundClock::new(TimingProfile::normal(), round.start_time),
+            history: vec![],
+            round,
+            player_limits: LimitCache::new(limits),
+        }
+    }
+
+    pub fn with_timing(mut self, profile: TimingProfile) -> Table<G> {
+        self.clock = RoundClock::new(profile, self.round.start_time);
+        self
+    }
+
+    // closes betting when its window is over, errors when the dealer is late.
+    pub fn tick(&mut self, now: u64) -> Result<Phase, RoundError> {
+        self.clock.tick(now)
+    }
+
+    pub fn close_betting(&mut self, now: u64) -> Result<(), RoundError> {
+        self.clock.close_betting(now)
+    }
+
+    pub fn dealt(&mut self, now: u64) -> Result<(), RoundError> {
+        self.clock.dealt(now)
+    }
+
+    pub fn confirm(&mut self, now: u64) -> Result<(), RoundError> {
+        self.clock.confirm(now)?;
+        self.round.end_time = now;
+        Ok(())
+    }
+
+    // calls off the round, every bet of it is refunded.
+    pub fn void(&mut self, now: u64) -> Result<Vec<SettlementRecord>, RoundError> {
+        self.clock.void(now)?;
+        self.round.end_time = now;
+        let refunds: HashMap<u16, f64> = self.current_bets
+            .iter()
+            .flat_map(|pb| pb.bets.keys().map(|k| (k.to_u16(), 1.0)))
+            .collect();
+        let records = self.records(&self.current_bets, &refunds);
+        self.current_bets.clear();
+        Ok(records)
+    }
+
     pub fn bet(
         &mut self,
-        _user_id: &str,
-        _min_limit: f64,
-        _max_limit: f64,
-        _round_id: u64,
+        user_id: &str,
+        profile: &str,
+        round_id: u64,
         bets: HashMap<u16, f64>,
-    ) -> f64 {
-        let _checked_bets = self.game.from_raw_bets(&bets);
-        32.0
+        now: u64,
+    ) -> Result<f64, BetError> {
+        if round_id != self.round.id {
+            return Err(BetError::RoundMismatch);
+        }
+        if self.clock.tick(now)? != Phase::Betting {
+            return Err(BetError::BettingClosed);
+        }
+        let checked_bets = match self.game.from_raw_bets(&bets) {
+            Some(b) => b,
+            None => return Err(BetError::InvalidBets),
+        };
+        if self.profile_changed(user_id, profile) {
+            return Err(BetError::LimitChanged);
+        }
+        let mut placed = self.placed_bets(user_id);
+        let before = self.offset.bet_offset(&placed);
+        for (&k, &v) in &bets {
+            *placed.entry(k).or_insert(0.0) += v;
+        }
+        match self.player_limits.profile(user_id, profile) {
+            Some(p) => p.check(&placed)?,
+            None => return Err(BetError::UnknownProfile),
+        }
+        let after = self.offset.bet_offset(&placed);
+        let mut offset = 0.0;
+        if self.offset.exceeded(after) {
+            match self.offset.action {
+                OffsetAction::Reject => return Err(BetError::OffsetExceeded(after)),
+                OffsetAction::Flag => offset = after - before,
+            }
+        }
+        let uuid = format!("{}-{}-{}", self.id, round_id, self.current_bets.len());
+        let pb = PlayerBet::new(
+            uuid,
+            user_id.to_string(),
+            profile.to_string(),
+            checked_bets,
+            offset,
+        );
+        let total = pb.total();
+        self.current_bets.push(pb);
+        Ok(total)
+    }
+
+    // payouts is the payout map of the round keyed by bet id.
+    pub fn settle(&self, payouts: &HashMap<u16, f64>) -> Vec<SettlementRecord> {
+        self.records(&self.current_bets, payouts)
+    }
+
+    // a squeezed hand is settled only once every required card is revealed,
+    // the hand is then kept in the history of the shoe.
+    pub fn settle_squeeze<F>(&mut self, squeeze: &SqueezeDealer, payout_map: F) -> Result<Vec<SettlementRecord>, SqueezeError>
+    where
+        F: Fn(&Baccarat) -> HashMap<G::B, f64>,
+    {
+        let b = squeeze.result()?;
+        let map = payout_map(&b);
+        let payouts: HashMap<u16, f64> = map.iter().map(|(k, &v)| (k.to_u16(), v)).collect();
+        let records = self.settle(&payouts);
+        let detail = HandDetail::new(&b).with_side_bets(&map, self.game.main_bets());
+        self.history.push(detail);
+        Ok(records)
+    }
+
+    // moves the bets out of the current round, to be settled on the next spin (En Prison).
+    pub fn imprison(&mut self, bet_ids: &[u16]) {
+        let policy = &self.offset;
+        for pb in &mut self.current_bets {
+            let hedged = hedged_stake(policy, pb);
+            let (moved, kept): (HashMap<G::B, f64>, HashMap<G::B, f64>) = mem::take(&mut pb.bets)
+                .into_iter()
+                .partition(|(k, _)| bet_ids.contains(&k.to_u16()));
+            pb.bets = kept;
+            if moved.is_empty() {
+                continue;
+            }
+            let mut offset = 0.0;
+            if hedged > 0.0 {
+                let moved_hedged = moved
+                    .iter()
+                    .filter(|&(k, _)| policy.covers(k.to_u16()))
+                    .fold(0.0, |a, (_, v)| a + v);
+                offset = pb.offset * moved_hedged / hedged;
+                pb.offset -= offset;
+            }
+            self.imprisoned_bets.push(PlayerBet::new(
+                pb.uuid.clone(),
+                pb.user_id.clone(),
+                pb.profile.clone(),
+                moved,
+                offset,
+            ));
+        }
+        self.current_bets.retain(|pb| !pb.bets.is_empty());
+    }
+
+    pub fn settle_imprisoned(&mut self, payouts: &HashMap<u16, f64>) -> Vec<SettlementRecord> {
+        let bets = mem::take(&mut self.imprisoned_bets);
+        self.records(&bets, payouts)
+    }
+
+    // the flagged offset of a player bet is shared by its hedged bets.
+    fn records(&self, bets: &[PlayerBet<G::B>], payouts: &HashMap<u16, f64>) -> Vec<SettlementRecord> {
+        let mut records = vec![];
+        for pb in bets {
+            let hedged = hedged_stake(&self.offset, pb);
+            for (k, &stake) in &pb.bets {
+                let bet_id = k.to_u16();
+                let offset = if hedged > 0.0 && self.offset.covers(bet_id) {
+                    pb.offset * stake / hedged
+                } else {
+                    0.0
+                };
+                records.push(SettlementRecord {
+                    table_id: self.id,
+                    game: self.game.name(),
+                    round_id: self.round.id,
+                    hour: self.round.end_time / 3600,
+                    user_id: pb.user_id.clone(),
+                    bet_id,
+                    stake,
+                    payout: stake * payouts.get(&bet_id).cloned().unwrap_or(0.0),
+                    offset,
+                });
+            }
+        }
+        records
+    }
+
+    // the limit profile is fixed for the whole shoe once the player has bets on the table.
+    fn profile_changed(&self, user_id: &str, profile: &str) -> bool {
+        self.previous_bets
+            .iter()
+            .chain(self.current_bets.iter())
+            .any(|pb| pb.user_id == user_id && pb.profile != profile)
+    }
+
+    // hand 1 starts a new shoe, which releases the limit profiles and reloads them.
+    pub fn next_round(&mut self, round: Round) {
+        let bets = mem::take(&mut self.current_bets);
+        if round.hand <= 1 {
+            self.previous_bets.clear();
+            self.history.clear();
+            self.player_limits.clear();
+        } else {
+            self.previous_bets.extend(bets);
+        }
+        self.clock = RoundClock::new(self.clock.profile, round.start_time);
+        self.round = round;
+    }
+
+    // all bets of the user in current round, merged by bet id.
+    pub fn placed_bets(&self, user_id: &str) -> HashMap<u16, f64> {
+        let mut m = HashMap::<u16, f64>::new();
+        for pb in self.current_bets.iter().filter(|pb| pb.user_id == user_id) {
+            for (k, &v) in &pb.bets {
+                *m.entry(k.to_u16()).or_insert(0.0) += v;
+            }
+        }
+        m
+    }
+}
+
+fn hedged_stake<T: Eq + Hash + BetSerde>(offset: &OffsetPolicy, pb: &PlayerBet<T>) -> f64 {
+    pb.bets
+        .iter()
+        .filter(|&(k, _)| offset.covers(k.to_u16()))
+        .fold(0.0, |a, (_, v)| a + v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use games::card::baccarat::commission_baccarat::CommissionBaccaratGame;
+    use games::dice::roulette::{EvenMoney, Wheel};
+    use games::dice::roulette::all::Roulette;
+    use tables::limits::LimitProfile;
+    use tables::offset::{baccarat_rules, roulette_rules};
+
+    struct Limits;
+
+    impl LimitSource for Limits {
+        fn load_player_limits(&self, _user_id: &str) -> Vec<LimitProfile> {
+            vec![
+                LimitProfile::new("regular", Limit::new(1.0, 1000.0), 2000.0)
+                    .with_bet(3, Limit::new(1.0, 100.0)),
+                LimitProfile::new("vip", Limit::new(100.0, 10000.0), 20000.0),
+            ]
+        }
+    }
+
+    fn round(id: u64, hand: u8) -> Round {
+        Round {
+            id,
+            hand,
+            start_time: 0,
+            end_time: 0,
+        }
+    }
+
+    fn table(max_bet_offset: f64, action: OffsetAction) -> Table<CommissionBaccaratGame> {
+        let offset = OffsetPolicy::new(baccarat_rules(), max_bet_offset, action);
+        Table::new(1, CommissionBaccaratGame, round(1, 1), offset, Box::new(Limits))
+    }
+
+    #[test]
+    fn test_bet() {
+        let mut t = table(0.0, OffsetAction::Reject);
+        assert_eq!(t.bet("u1", "regular", 2, hashmap!{1=>10.0}, 0), Err(BetError::RoundMismatch));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{99=>10.0}, 0), Err(BetError::InvalidBets));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>10.0, 3=>5.0}, 0), Ok(15.0));
+        assert_eq!(t.current_bets.len(), 1);
+    }
+
+    #[test]
+    fn test_bet_offset_reject() {
+        let mut t = table(30.0, OffsetAction::Reject);
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>100.0, 2=>20.0}, 0), Ok(120.0));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{2=>20.0}, 0), Err(BetError::OffsetExceeded(40.0)));
+        assert_eq!(t.bet("u2", "regular", 1, hashmap!{2=>20.0}, 0), Ok(20.0));
+        assert_eq!(t.current_bets.len(), 2);
+    }
+
+    #[test]
+    fn test_bet_offset_flag() {
+        let mut t = table(30.0, OffsetAction::Flag);
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>100.0, 2=>20.0}, 0), Ok(120.0));
+        assert_eq!(t.current_bets[0].offset, 0.0);
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{2=>20.0, 3=>10.0}, 0), Ok(30.0));
+        assert_eq!(t.current_bets[1].offset, 20.0);
+        assert_eq!(t.current_bets[1].turnover(), 10.0);
+    }
+
+    #[test]
+    fn test_bet_limits() {
+        let mut t = table(1000.0, OffsetAction::Reject);
+        assert_eq!(t.bet("u1", "gold", 1, hashmap!{1=>10.0}, 0), Err(BetError::UnknownProfile));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{3=>80.0}, 0), Ok(80.0));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{3=>30.0}, 0), Err(BetError::AboveMax(3)));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>1000.0, 2=>1000.0}, 0), Err(BetError::TableMaxExceeded));
+        assert_eq!(t.bet("u2", "vip", 1, hashmap!{1=>50.0}, 0), Err(BetError::BelowMin(1)));
+    }
+
+    #[test]
+    fn test_limit_changed() {
+        let mut t = table(1000.0, OffsetAction::Reject);
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>100.0}, 0), Ok(100.0));
+        assert_eq!(t.bet("u1", "vip", 1, hashmap!{1=>100.0}, 0), Err(BetError::LimitChanged));
+        t.next_round(round(2, 2));
+        assert_eq!(t.bet("u1", "vip", 2, hashmap!{1=>100.0}, 0), Err(BetError::LimitChanged));
+        assert_eq!(t.previous_bets.len(), 1);
+        t.next_round(round(3, 1));
+        assert_eq!(t.bet("u1", "vip", 3, hashmap!{1=>100.0}, 0), Ok(100.0));
+        assert!(t.previous_bets.is_empty());
+    }
+
+    #[test]
+    fn test_settle() {
+        let mut t = table(10.0, OffsetAction::Flag);
+        t.round.end_time = 7200;
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>100.0}, 0), Ok(100.0));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{2=>40.0, 3=>10.0}, 0), Ok(50.0));
+        let mut records = t.settle(&hashmap!{1=>1.0, 2=>1.0, 3=>9.0});
+        records.sort_by_key(|r| r.bet_id);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].payout, 100.0);
+        assert_eq!(records[1].offset, 40.0);
+        assert_eq!(records[2].payout, 90.0);
+        assert_eq!(records[2].offset, 0.0);
+        assert_eq!(records[2].hour, 2);
+        assert_eq!(records[2].game, "commission_baccarat");
+    }
+
+    #[test]
+    fn test_settle_squeeze() {
+        use games::card::baccarat::commission_baccarat::payout_map;
+        use games::card::baccarat::squeeze::Position;
+        use games::card::serde::str_to_card;
+
+        let mut t = table(0.0, OffsetAction::Reject);
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>100.0}, 0), Ok(100.0));
+        let mut d = SqueezeDealer::new();
+        for _ in 0..4 {
+            d.deal_hidden().unwrap();
+        }
+        d.reveal(Position::Player(1), str_to_card("ST").unwrap()).unwrap();
+        d.reveal(Position::Banker(1), str_to_card("S9").unwrap()).unwrap();
+        d.reveal(Position::Player(2), str_to_card("H2").unwrap()).unwrap();
+        assert_eq!(t.settle_squeeze(&d, payout_map).err(), Some(SqueezeError::CardsHidden));
+        assert!(t.history.is_empty());
+        d.reveal(Position::Banker(2), str_to_card("DQ").unwrap()).unwrap();
+        let records = t.settle_squeeze(&d, payout_map).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].payout, 195.0);
+        // banker natural 9 and small win, the main bets stay out of the side bets
+        assert_eq!(t.history.len(), 1);
+        assert_eq!(t.history[0].card_strs(), vec!["ST", "S9", "H2", "DQ"]);
+        assert_eq!(t.history[0].side_bets, vec![7, 12, 13]);
+
+        t.next_round(round(2, 2));
+        assert_eq!(t.history.len(), 1);
+        t.next_round(round(3, 1));
+        assert!(t.history.is_empty());
+    }
+
+    #[test]
+    fn test_timing() {
+        let mut t = table(0.0, OffsetAction::Reject).with_timing(TimingProfile::speed());
+        assert_eq!(t.tick(11), Ok(Phase::Betting));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>10.0}, 11), Ok(10.0));
+        assert_eq!(t.tick(12), Ok(Phase::Dealing));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>10.0}, 12), Err(BetError::BettingClosed));
+        assert_eq!(t.dealt(25), Ok(()));
+        assert_eq!(t.confirm(31), Err(RoundError::Timeout(Phase::Confirming)));
+        assert_eq!(t.round.end_time, 0);
+
+        t.next_round(Round {
+            id: 2,
+            hand: 2,
+            start_time: 100,
+            end_time: 0,
+        });
+        assert_eq!(t.clock.phase(), Phase::Betting);
+        assert_eq!(t.clock.deadline(), Some(112));
+        assert_eq!(t.close_betting(105), Ok(()));
+        assert_eq!(t.dealt(110), Ok(()));
+        assert_eq!(t.confirm(112), Ok(()));
+        assert_eq!(t.round.end_time, 112);
+    }
+
+    #[test]
+    fn test_late_bet() {
+        let mut t = table(0.0, OffsetAction::Reject).with_timing(TimingProfile::speed());
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>10.0}, 11), Ok(10.0));
+        // nobody ticked, the bet itself closes the window
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>10.0}, 12), Err(BetError::BettingClosed));
+        assert_eq!(t.clock.phase(), Phase::Dealing);
+        assert_eq!(t.bet("u2", "regular", 1, hashmap!{1=>10.0}, 100), Err(BetError::BettingClosed));
+        assert_eq!(t.current_bets.len(), 1);
+    }
+
+    #[test]
+    fn test_void() {
+        let mut t = table(0.0, OffsetAction::Reject).with_timing(TimingProfile::speed());
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>10.0, 3=>5.0}, 5), Ok(15.0));
+        assert_eq!(t.tick(40), Err(RoundError::Timeout(Phase::Dealing)));
+        let mut records = t.void(40).unwrap();
+        records.sort_by_key(|r| r.bet_id);
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].stake, records[0].payout), (10.0, 10.0));
+        assert_eq!((records[1].stake, records[1].payout), (5.0, 5.0));
+        assert_eq!(t.round.end_time, 40);
+        assert!(t.current_bets.is_empty());
+        assert_eq!(t.void(41).err(), Some(RoundError::WrongPhase(Phase::Voided)));
+
+        t.next_round(Round {
+            id: 2,
+            hand: 2,
+            start_time: 50,
+            end_time: 0,
+        });
+        assert_eq!(t.clock.phase(), Phase::Betting);
+        assert_eq!(t.bet("u1", "regular", 2, hashmap!{1=>10.0}, 51), Ok(10.0));
+        assert!(t.previous_bets.is_empty());
+    }
+
+    #[test]
+    fn test_en_prison() {
+        let game = Roulette::with_rules(Wheel::European, EvenMoney::EnPrison);
+        let offset = OffsetPolicy::new(roulette_rules(), 0.0, OffsetAction::Flag);
+        let mut t = Table::new(1, game, round(1, 1), offset, Box::new(Limits));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{999=>10.0}, 0), Err(BetError::InvalidBets));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{1=>100.0, 100=>10.0}, 0), Ok(110.0));
+        assert_eq!(t.bet("u1", "regular", 1, hashmap!{2=>20.0}, 0), Ok(20.0));
+
+        let ids = t.game.imprisoned(0);
+        t.imprison(&ids);
+        let records = t.settle(&t.game.payout_map(0));
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].payout, 360.0);
+        assert_eq!(t.imprisoned_bets.len(), 2);
+        assert_eq!(t.imprisoned_bets[1].offset, 20.0);
+
+        t.next_round(round(2, 2));
+        let mut records = t.settle_imprisoned(&t.game.prison_payout_map(1));
+        records.sort_by_key(|r| r.bet_id);
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].bet_id, records[0].payout, records[0].round_id), (1, 100.0, 2));
+        assert_eq!((records[1].bet_id, records[1].payout, records[1].offset), (2, 0.0, 20.0));
+        assert!(t.imprisoned_bets.is_empty());
     }
 }
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use super::common::{BetError, Limit};
+
+pub struct LimitProfile {
+    pub name: String,
+    pub default: Limit,
+    pub bets: HashMap<u16, Limit>,
+    pub table_max: f64,
+}
+
+impl LimitProfile {
+    pub fn new(name: &str, default: Limit, table_max: f64) -> LimitProfile {
+        LimitProfile {
+            name: name.to_string(),
+            default,
+            bets: HashMap::new(),
+            table_max,
+        }
+    }
+
+    // override the default limit for a single bet type, e.g. Tie or Super6.
+    pub fn with_bet(mut self, bet_id: u16, limit: Limit) -> LimitProfile {
+        self.bets.insert(bet_id, limit);
+        self
+    }
+
+    pub fn limit(&self, bet_id: u16) -> &Limit {
+        self.bets.get(&bet_id).unwrap_or(&self.default)
+    }
+
+    // bets are all the bets of the player in the round, merged by bet id.
+    pub fn check(&self, bets: &HashMap<u16, f64>) -> Result<(), BetError> {
+        let mut total = 0.0;
+        for (&id, &v) in bets {
+            let limit = self.limit(id);
+            if v < limit.min() {
+                return Err(BetError::BelowMin(id));
+            }
+            if v > limit.max() {
+                return Err(BetError::AboveMax(id));
+            }
+            total += v;
+        }
+        if total > self.table_max {
+            return Err(BetError::TableMaxExceeded);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let p = LimitProfile::new("regular", Limit::new(10.0, 1000.0), 1500.0)
+            .with_bet(3, Limit::new(5.0, 100.0));
+        assert_eq!(p.check(&hashmap!{1=>10.0, 3=>5.0}), Ok(()));
+        assert_eq!(p.check(&hashmap!{1=>5.0}), Err(BetError::BelowMin(1)));
+        assert_eq!(p.check(&hashmap!{3=>200.0}), Err(BetError::AboveMax(3)));
+        assert_eq!(p.check(&hashmap!{1=>1000.0, 2=>1000.0}), Err(BetError::TableMaxExceeded));
+    }
+}
@@ -1,4 +1,7 @@
 pub mod common;
+pub mod offset;
+pub mod limits;
+pub mod timing;
 
 /*
 struct Round {
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OffsetAction {
+    Reject,
+    Flag,
+}
+
+// a pair of bet ids which cover opposite outcomes, e.g. Banker and Player.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OffsetRule(pub u16, pub u16);
+
+impl OffsetRule {
+    // the hedged part is the stake matched on both sides.
+    pub fn offset(&self, bets: &HashMap<u16, f64>) -> f64 {
+        let a = bets.get(&self.0).cloned().unwrap_or(0.0);
+        let b = bets.get(&self.1).cloned().unwrap_or(0.0);
+        a.min(b)
+    }
+}
+
+pub struct OffsetPolicy {
+    pub rules: Vec<OffsetRule>,
+    pub max_bet_offset: f64,
+    pub action: OffsetAction,
+}
+
+impl OffsetPolicy {
+    pub fn new(rules: Vec<OffsetRule>, max_bet_offset: f64, action: OffsetAction) -> OffsetPolicy {
+        OffsetPolicy {
+            rules,
+            max_bet_offset,
+            action,
+        }
+    }
+
+    pub fn none() -> OffsetPolicy {
+        OffsetPolicy::new(vec![], 0.0, OffsetAction::Flag)
+    }
+
+    pub fn bet_offset(&self, bets: &HashMap<u16, f64>) -> f64 {
+        self.rules.iter().fold(0.0, |a, r| a + r.offset(bets))
+    }
+
+    pub fn covers(&self, bet_id: u16) -> bool {
+        self.rules.iter().any(|r| r.0 == bet_id || r.1 == bet_id)
+    }
+
+    pub fn exceeded(&self, offset: f64) -> bool {
+        offset > self.max_bet_offset
+    }
+}
+
+pub fn baccarat_rules() -> Vec<OffsetRule> {
+    //banker vs player
+    vec![OffsetRule(1, 2)]
+}
+
+pub fn dragontiger_rules() -> Vec<OffsetRule> {
+    //dragon vs tiger
+    vec![OffsetRule(1, 2)]
+}
+
+pub fn roulette_rules() -> Vec<OffsetRule> {
+    //red vs black, odd vs even, high vs low
+    vec![OffsetRule(1, 2), OffsetRule(3, 4), OffsetRule(5, 6)]
+}
+
+pub fn sicbo_rules() -> Vec<OffsetRule> {
+    //big vs small, odd vs even
+    vec![OffsetRule(1, 2), OffsetRule(3, 4)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bet_offset() {
+        let p = OffsetPolicy::new(baccarat_rules(), 50.0, OffsetAction::Reject);
+        assert_eq!(p.bet_offset(&hashmap!{1=>100.0}), 0.0);
+        assert_eq!(p.bet_offset(&hashmap!{1=>100.0, 2=>30.0, 3=>10.0}), 30.0);
+        assert_eq!(p.exceeded(30.0), false);
+        assert_eq!(p.bet_offset(&hashmap!{1=>80.0, 2=>200.0}), 80.0);
+        assert_eq!(p.exceeded(80.0), true);
+    }
+
+    #[test]
+    fn test_roulette_offset() {
+        let p = OffsetPolicy::new(roulette_rules(), 0.0, OffsetAction::Flag);
+        let bets = hashmap!{1=>10.0, 2=>10.0, 3=>5.0, 4=>20.0, 5=>50.0, 101=>10.0};
+        assert_eq!(p.bet_offset(&bets), 15.0);
+    }
+}
@@ -0,0 +1,197 @@
+// round lifecycle of a table, every time is in seconds
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    Betting,
+    Dealing,
+    Confirming,
+    Closed,
+    // called off before the result was confirmed, bets are refunded
+    Voided,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundError {
+    WrongPhase(Phase),
+    // the dealer missed the deadline of the phase
+    Timeout(Phase),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TimingProfile {
+    pub name: &'static str,
+    pub betting: u64,
+    pub dealing: u64,
+    pub confirmation: u64,
+}
+
+impl TimingProfile {
+    pub fn normal() -> TimingProfile {
+        TimingProfile {
+            name: "normal",
+            betting: 20,
+            dealing: 30,
+            confirmation: 10,
+        }
+    }
+
+    pub fn speed() -> TimingProfile {
+        TimingProfile {
+            name: "speed",
+            betting: 12,
+            dealing: 20,
+            confirmation: 5,
+        }
+    }
+
+    pub fn no_commission_speed() -> TimingProfile {
+        TimingProfile {
+            name: "no_commission_speed",
+            ..TimingProfile::speed()
+        }
+    }
+
+    // time allowed in the phase, None once the round is closed
+    pub fn window(&self, phase: Phase) -> Option<u64> {
+        match phase {
+            Phase::Betting => Some(self.betting),
+            Phase::Dealing => Some(self.dealing),
+            Phase::Confirming => Some(self.confirmation),
+            Phase::Closed | Phase::Voided => None,
+        }
+    }
+}
+
+// the phase of the current round and when it started
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RoundClock {
+    pub profile: TimingProfile,
+    phase: Phase,
+    since: u64,
+}
+
+impl RoundClock {
+    pub fn new(profile: TimingProfile, start_time: u64) -> RoundClock {
+        RoundClock {
+            profile,
+            phase: Phase::Betting,
+            since: start_time,
+        }
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    pub fn deadline(&self) -> Option<u64> {
+        self.profile.window(self.phase).map(|w| self.since + w)
+    }
+
+    // betting closes by itself at its deadline, the dealer phases time out.
+    pub fn tick(&mut self, now: u64) -> Result<Phase, RoundError> {
+        if self.phase == Phase::Betting {
+            if let Some(d) = self.deadline() {
+                if now >= d {
+                    self.phase = Phase::Dealing;
+                    self.since = d;
+                }
+            }
+        }
+        match self.deadline() {
+            Some(d) if self.phase != Phase::Betting && now > d => Err(RoundError::Timeout(self.phase)),
+            _ => Ok(self.phase),
+        }
+    }
+
+    fn advance(&mut self, now: u64, from: Phase, to: Phase) -> Result<(), RoundError> {
+        if self.tick(now)? != from {
+            return Err(RoundError::WrongPhase(self.phase));
+        }
+        self.phase = to;
+        self.since = now;
+        Ok(())
+    }
+
+    // the dealer may close betting before the deadline
+    pub fn close_betting(&mut self, now: u64) -> Result<(), RoundError> {
+        self.advance(now, Phase::Betting, Phase::Dealing)
+    }
+
+    pub fn dealt(&mut self, now: u64) -> Result<(), RoundError> {
+        self.advance(now, Phase::Dealing, Phase::Confirming)
+    }
+
+    pub fn confirm(&mut self, now: u64) -> Result<(), RoundError> {
+        self.advance(now, Phase::Confirming, Phase::Closed)
+    }
+
+    // ends a round which cannot be confirmed, a timed out one included
+    pub fn void(&mut self, now: u64) -> Result<(), RoundError> {
+        match self.phase {
+            Phase::Closed | Phase::Voided => Err(RoundError::WrongPhase(self.phase)),
+            _ => {
+                self.phase = Phase::Voided;
+                self.since = now;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles() {
+        assert_eq!(TimingProfile::normal().betting, 20);
+        assert_eq!(TimingProfile::speed().betting, 12);
+        let p = TimingProfile::no_commission_speed();
+        assert_eq!((p.name, p.betting), ("no_commission_speed", 12));
+    }
+
+    #[test]
+    fn test_lifecycle() {
+        let mut c = RoundClock::new(TimingProfile::speed(), 100);
+        assert_eq!(c.deadline(), Some(112));
+        assert_eq!(c.tick(111), Ok(Phase::Betting));
+        assert_eq!(c.dealt(111), Err(RoundError::WrongPhase(Phase::Betting)));
+        assert_eq!(c.tick(112), Ok(Phase::Dealing));
+        assert_eq!(c.deadline(), Some(132));
+        assert_eq!(c.dealt(130), Ok(()));
+        assert_eq!(c.deadline(), Some(135));
+        assert_eq!(c.confirm(134), Ok(()));
+        assert_eq!(c.phase(), Phase::Closed);
+        assert_eq!(c.deadline(), None);
+        assert_eq!(c.tick(1000), Ok(Phase::Closed));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut c = RoundClock::new(TimingProfile::normal(), 0);
+        assert_eq!(c.close_betting(5), Ok(()));
+        assert_eq!(c.tick(35), Ok(Phase::Dealing));
+        assert_eq!(c.dealt(36), Err(RoundError::Timeout(Phase::Dealing)));
+        // a late tick closes betting and times out the dealing at once
+        let mut c = RoundClock::new(TimingProfile::normal(), 0);
+        assert_eq!(c.tick(51), Err(RoundError::Timeout(Phase::Dealing)));
+        assert_eq!(c.phase(), Phase::Dealing);
+    }
+
+    #[test]
+    fn test_void() {
+        let mut c = RoundClock::new(TimingProfile::speed(), 0);
+        assert_eq!(c.tick(40), Err(RoundError::Timeout(Phase::Dealing)));
+        assert_eq!(c.void(40), Ok(()));
+        assert_eq!(c.phase(), Phase::Voided);
+        assert_eq!(c.deadline(), None);
+        assert_eq!(c.tick(1000), Ok(Phase::Voided));
+        assert_eq!(c.void(41), Err(RoundError::WrongPhase(Phase::Voided)));
+        assert_eq!(c.dealt(41), Err(RoundError::WrongPhase(Phase::Voided)));
+
+        let mut c = RoundClock::new(TimingProfile::speed(), 0);
+        assert_eq!(c.close_betting(1), Ok(()));
+        assert_eq!(c.dealt(2), Ok(()));
+        assert_eq!(c.confirm(3), Ok(()));
+        assert_eq!(c.void(4), Err(RoundError::WrongPhase(Phase::Closed)));
+    }
+}